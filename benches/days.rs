@@ -0,0 +1,99 @@
+//! Benchmarks every day on the real inputs, on stable Rust:
+//!
+//! ```text
+//! cargo bench                                   # all days
+//! cargo bench --bench days -- 6 16              # only some days
+//! cargo bench --bench days -- --iterations 200  # see `--help` for more
+//! ```
+//!
+//! Results are printed and also written as TSV to `bench_output.txt`.
+
+use advent_of_code_2024::{
+    bench::BenchArgs, input::InputSource, registry, report::Format, threads::ThreadArgs, Parser,
+    Parts,
+};
+use mimalloc::MiMalloc;
+use std::{fs, path::PathBuf, process::ExitCode};
+
+#[global_allocator]
+static GLOBAL_ALLOC: MiMalloc = MiMalloc;
+
+#[derive(Parser)]
+struct BenchCli {
+    /// Days to benchmark, or every day if none are given
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+
+    /// Directory containing `real/` and `examples/` inputs
+    #[clap(long, env = "AOC_INPUT_DIR", default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Which parts to benchmark
+    #[clap(long, value_enum, default_value_t)]
+    part: Parts,
+
+    /// File to write the results to, as TSV
+    #[clap(long, default_value = "bench_output.txt")]
+    output: PathBuf,
+
+    // `cargo bench` always passes `--bench`, which turns benchmarking on.
+    #[clap(flatten)]
+    bench: BenchArgs,
+
+    #[clap(flatten)]
+    threads: ThreadArgs,
+}
+
+fn main() -> ExitCode {
+    let mut args = BenchCli::parse();
+    args.bench.enabled = true;
+    args.threads.apply();
+
+    let mut tsv = Format::Tsv.header().unwrap_or_default().to_string() + "\n";
+    let mut failed = false;
+
+    for day in registry::DAYS
+        .iter()
+        .filter(|d| args.days.is_empty() || args.days.contains(&d.day))
+    {
+        let source = InputSource::for_day(&args.input_dir, day, false);
+        let inp = match source.read() {
+            Ok(inp) => inp,
+            Err(e) => {
+                println!(
+                    "2024 Day {:02}: skipped, can't open {}: {}",
+                    day.day, source, e
+                );
+                continue;
+            }
+        };
+
+        match args.bench.run(day, &inp, args.part) {
+            Ok(report) => {
+                if let Some(stats) = report.bench {
+                    println!("2024 Day {:02}: {}", day.day, stats);
+                }
+                tsv += &report.render(Format::Tsv);
+                if let Err(e) = args.threads.compare(day, &inp, args.part, &report) {
+                    eprintln!("2024 Day {:02}: {}", day.day, e);
+                    failed = true;
+                }
+            }
+            Err(e) => {
+                eprintln!("2024 Day {:02}: can't parse {}: {}", day.day, source, e);
+                failed = true;
+            }
+        }
+    }
+
+    if let Err(e) = fs::write(&args.output, tsv) {
+        eprintln!("can't write {}: {}", args.output.display(), e);
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -2,12 +2,15 @@ set -e
 
 HYPERFINE_RUN_ARGS="--warmup=5 --runs 25"
 
+# Which parts to profile: 1, 2 or both
+PART="${PART:-both}"
+
 mkdir -p profiling-data
 
 for i in $(seq -w 1 25) 
 do 
     if test -f "./target/release/2024_$i"; then
-        CMD="./target/release/2024_$i --input inputs/real/2024_$i"
+        CMD="./target/release/2024_$i --input inputs/real/2024_$i --part $PART"
         perf record -g -F max $CMD
         perf script -F +pid > profiling-data/2024_$i.perf
         rm perf.data
@@ -1,6 +1,6 @@
 set -e
 
-HYPERFINE_RUN_ARGS="--warmup=10 --runs 50"
+BENCH_ARGS="--bench --warmup 10 --iterations 50"
 
 for i in $(seq -w 1 25) 
 do 
@@ -8,13 +8,14 @@ do
         CMD="./target/release/2024_$i --input inputs/real/2024_$i"
         echo ""
         echo "2024 Day $i"
-        $CMD
+        # Answers, then in-process benchmark of the solver
+        $CMD $BENCH_ARGS
         echo ""
-        # Main benchmarking
-        hyperfine $HYPERFINE_RUN_ARGS -u millisecond --style basic "$CMD" 2>/dev/null
         
         # CPU energy usage benchmark
-        CPU_JOULES_ITER=20
-        perf stat -r $CPU_JOULES_ITER -e power/energy-pkg/ -- $CMD 2>&1 >/dev/null | grep -F "Joules"
+        if command -v perf > /dev/null; then
+            CPU_JOULES_ITER=20
+            perf stat -r $CPU_JOULES_ITER -e power/energy-pkg/ -- $CMD 2>&1 >/dev/null | grep -F "Joules"
+        fi
     fi
 done;
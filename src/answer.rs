@@ -0,0 +1,126 @@
+use serde::Serialize;
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle, in a form common to every day.
+///
+/// Serializes untagged: integers as JSON numbers, text as strings and a
+/// missing answer as `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// The part has no answer, e.g. day 25 part 2.
+    None,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+/// Parses an answer as written by `Display`: integers become `Integer`, an
+/// empty string becomes `None` and anything else is `Text`.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if s.is_empty() {
+            Answer::None
+        } else if let Ok(n) = s.parse() {
+            Answer::Integer(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i16, i32, i64, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(5067_usize), Answer::Integer(5067));
+        assert_eq!(Answer::from(-3_i32), Answer::Integer(-3));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Integer(18446744073709551615)
+        );
+        assert_eq!(
+            Answer::from("co,de,ka,ta"),
+            Answer::Text("co,de,ka,ta".to_string())
+        );
+        assert_eq!(Answer::from(()), Answer::None);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for answer in [
+            Answer::Integer(265652340990875),
+            Answer::Integer(-12),
+            Answer::Text("1,0,2,0,5,7,2,1,3".to_string()),
+            Answer::None,
+        ] {
+            assert_eq!(answer.to_string().parse(), Ok(answer));
+        }
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Answer::Integer(1793)).unwrap(),
+            "1793"
+        );
+        assert_eq!(
+            serde_json::to_string(&Answer::Text("60,37".to_string())).unwrap(),
+            "\"60,37\""
+        );
+        assert_eq!(serde_json::to_string(&Answer::None).unwrap(), "null");
+    }
+}
@@ -0,0 +1,267 @@
+use crate::bench::BenchArgs;
+use crate::input::InputSource;
+use crate::manifest::{Check, Manifest};
+use crate::registry::Day;
+use crate::report::{Format, Report};
+use crate::threads::ThreadArgs;
+use crate::Parts;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::path::Path;
+
+/// Solves one day for many inputs at once, e.g. everyone's personal inputs.
+pub struct Batch<'a> {
+    pub day: &'a Day,
+    pub parts: Parts,
+    pub bench: BenchArgs,
+    pub threads: ThreadArgs,
+    pub manifest: Option<&'a Manifest>,
+    pub input_dir: &'a Path,
+}
+
+/// The outcome of solving one input of a batch.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchEntry {
+    pub input: String,
+    #[serde(flatten)]
+    pub report: Option<Report>,
+    /// Why the input couldn't be solved, or why its answers are suspect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BatchEntry {
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+            || self
+                .report
+                .as_ref()
+                .is_some_and(|report| matches!(report.check, Some(Check::Fail { .. })))
+    }
+
+    /// A short summary for the table: the error, or the check's outcome.
+    fn status(&self) -> String {
+        match (&self.error, &self.report) {
+            (Some(error), _) => error.clone(),
+            (
+                None,
+                Some(Report {
+                    check: Some(check), ..
+                }),
+            ) => check.to_string(),
+            (None, _) => "ok".to_string(),
+        }
+    }
+}
+
+impl Batch<'_> {
+    /// Solves every input, in parallel across inputs, returning the outcomes
+    /// in the order the inputs were given. Timings are taken while other
+    /// inputs are being solved, so are only rough.
+    pub fn run(&self, sources: &[InputSource]) -> Vec<BatchEntry> {
+        sources
+            .par_iter()
+            .map(|source| self.run_one(source))
+            .collect()
+    }
+
+    fn run_one(&self, source: &InputSource) -> BatchEntry {
+        let entry = |report, error| BatchEntry {
+            input: source.to_string(),
+            report,
+            error,
+        };
+
+        let inp = match source.read() {
+            Ok(inp) => inp,
+            Err(e) => return entry(None, Some(format!("can't open input: {}", e))),
+        };
+
+        match self.bench.run(self.day, &inp, self.parts) {
+            Ok(mut report) => {
+                if let Some(manifest) = self.manifest {
+                    let key = source.manifest_key(self.input_dir);
+                    report.check = Some(manifest.check(key.as_deref(), &report, self.parts));
+                }
+                let error = self
+                    .threads
+                    .compare(self.day, &inp, self.parts, &report)
+                    .err();
+                entry(Some(report), error)
+            }
+            Err(e) => entry(None, Some(format!("can't parse input: {}", e))),
+        }
+    }
+}
+
+/// Renders a batch's outcomes: as an aligned table with a summary for text,
+/// otherwise one line per input with the input first.
+pub fn render(entries: &[BatchEntry], format: Format) -> String {
+    match format {
+        Format::Text => render_table(entries),
+        Format::Json => entries
+            .iter()
+            .map(|entry| {
+                serde_json::to_string(entry).expect("batch entries always serialize") + "\n"
+            })
+            .collect(),
+        Format::Tsv => {
+            let header = Format::Tsv.header().unwrap_or_default();
+            let empty_row = "\t".repeat(header.matches('\t').count());
+
+            let rows = entries.iter().map(|entry| {
+                let row = match &entry.report {
+                    Some(report) => report
+                        .render(Format::Tsv)
+                        .trim_end_matches('\n')
+                        .to_string(),
+                    None => empty_row.clone(),
+                };
+                let error = entry.error.as_deref().unwrap_or_default();
+                format!("{}\t{}\t{}\n", entry.input, row, error)
+            });
+            format!("input\t{}\terror\n", header) + &rows.collect::<String>()
+        }
+    }
+}
+
+fn render_table(entries: &[BatchEntry]) -> String {
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            let (part1, part2, time) = match &entry.report {
+                Some(report) => (
+                    report.part1.to_string(),
+                    report.part2.to_string(),
+                    format!("{:.3} ms", report.timings.total.as_secs_f64() * 1000.0),
+                ),
+                None => Default::default(),
+            };
+            [entry.input.clone(), part1, part2, time, entry.status()]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["input", "part 1", "part 2", "time", "status"].map(String::from);
+    let widths = [&header]
+        .into_iter()
+        .chain(&rows)
+        .fold([0; 5], |widths, row| {
+            std::array::from_fn(|i| widths[i].max(row[i].chars().count()))
+        });
+
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        table += line.trim_end();
+        table += "\n";
+    }
+
+    let failed = entries.iter().filter(|entry| entry.failed()).count();
+    table += &format!(
+        "{} inputs, {} ok, {} failed\n",
+        entries.len(),
+        entries.len() - failed,
+        failed
+    );
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Timings;
+    use crate::{registry, Answer};
+    use std::time::Duration;
+
+    fn entries() -> Vec<BatchEntry> {
+        vec![
+            BatchEntry {
+                input: "alice".to_string(),
+                report: Some(Report {
+                    day: 25,
+                    part1: Answer::Integer(3162),
+                    part2: Answer::None,
+                    timings: Timings {
+                        total: Duration::from_micros(42),
+                        ..Default::default()
+                    },
+                    bench: None,
+                    check: None,
+                }),
+                error: None,
+            },
+            BatchEntry {
+                input: "bob".to_string(),
+                report: None,
+                error: Some("can't open input: not found".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(&entries(), Format::Text),
+            "input  part 1  part 2  time      status
+alice  3162            0.042 ms  ok
+bob                              can't open input: not found
+2 inputs, 1 ok, 1 failed
+"
+        );
+        assert_eq!(
+            render(&entries(), Format::Json),
+            "{\"input\":\"alice\",\"day\":25,\"part1\":3162,\"part2\":null,\"timings\":{\"total_ns\":42000,\"parse_ns\":0,\"part1_ns\":0,\"part2_ns\":0}}
+{\"input\":\"bob\",\"error\":\"can't open input: not found\"}
+"
+        );
+
+        let tsv = render(&entries(), Format::Tsv);
+        let columns = tsv.lines().map(|line| line.split('\t').count());
+        assert!(columns.clone().all(|n| n == 15));
+        assert!(tsv.ends_with("bob\t\t\t\t\t\t\t\t\t\t\t\t\t\tcan't open input: not found\n"));
+    }
+
+    #[test]
+    fn test_run() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("good"), "1\n10\n100\n2024\n").unwrap();
+        std::fs::write(dir.join("bad"), "1\nten\n").unwrap();
+
+        let batch = Batch {
+            day: registry::get(22).unwrap(),
+            parts: Parts::One,
+            bench: BenchArgs {
+                enabled: false,
+                warmup: 0,
+                iterations: 1,
+            },
+            threads: ThreadArgs {
+                threads: None,
+                sequential: false,
+                compare_sequential: true,
+            },
+            manifest: None,
+            input_dir: &dir,
+        };
+        let sources = ["good", "bad", "missing"].map(|name| InputSource::File(dir.join(name)));
+        let entries = batch.run(&sources);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let failed = entries.iter().map(BatchEntry::failed).collect::<Vec<_>>();
+        assert_eq!(failed, [false, true, true]);
+        assert_eq!(
+            entries[0].report.as_ref().unwrap().part1,
+            Answer::Integer(37327623)
+        );
+        assert!(entries[1]
+            .status()
+            .starts_with("can't parse input: day 22, line 2"));
+        assert!(entries[2].status().starts_with("can't open input"));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::registry::Day;
+use crate::report::{as_nanos, Report};
+use crate::{ParseError, Parts};
+use clap::Args;
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Command line options for benchmarking, shared by the runners.
+#[derive(Debug, Clone, Copy, Args)]
+pub struct BenchArgs {
+    /// Time the solver in-process over many runs, excluding reading the input
+    #[clap(long = "bench")]
+    pub enabled: bool,
+
+    /// Untimed runs before benchmarking
+    #[clap(long, default_value_t = 10)]
+    pub warmup: u32,
+
+    /// Timed runs when benchmarking
+    #[clap(long, default_value_t = 50, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+}
+
+impl BenchArgs {
+    /// Runs the requested parts of `day`, benchmarking them too if asked to.
+    pub fn run(&self, day: &Day, raw_inp: &str, parts: Parts) -> Result<Report, ParseError> {
+        let mut report = day.run(raw_inp, parts)?;
+
+        if self.enabled {
+            report.bench = Some(bench(self.warmup, self.iterations, || {
+                day.solve(black_box(raw_inp), parts)
+            }));
+        }
+        Ok(report)
+    }
+}
+
+/// Summary of a number of timed iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BenchStats {
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single iteration.
+    #[serde(rename = "stddev_ns", serialize_with = "as_nanos")]
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Summarises some (non-empty) timings.
+    pub fn from_times(mut times: Vec<Duration>) -> BenchStats {
+        assert!(!times.is_empty(), "no timings to summarise");
+        times.sort_unstable();
+
+        let n = times.len();
+        let mean = times.iter().sum::<Duration>() / n as u32;
+
+        let variance = if n > 1 {
+            times
+                .iter()
+                .map(|t| (t.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        BenchStats {
+            iterations: n,
+            min: times[0],
+            median: if n.is_multiple_of(2) {
+                (times[n / 2 - 1] + times[n / 2]) / 2
+            } else {
+                times[n / 2]
+            },
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        write!(
+            f,
+            "{} runs: min {:.3} ms, median {:.3} ms, mean {:.3} ms ± {:.3} ms",
+            self.iterations,
+            ms(self.min),
+            ms(self.median),
+            ms(self.mean),
+            ms(self.stddev)
+        )
+    }
+}
+
+/// Runs `f` untimed `warmup` times, then times `iterations` further runs.
+pub fn bench<T>(warmup: u32, iterations: u32, mut f: impl FnMut() -> T) -> BenchStats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let times = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    BenchStats::from_times(times)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = BenchStats::from_times(
+            [4, 1, 3, 2]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1290);
+    }
+
+    #[test]
+    fn test_single_iteration() {
+        let stats = bench(0, 1, || 1 + 1);
+        assert_eq!(stats.iterations, 1);
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,155 @@
+use advent_of_code_2024::{
+    input::{self, InputSource},
+    manifest::Check,
+    registry,
+    report::Format,
+    runner::{load_manifest, run_batch},
+    Parser, RunArgs,
+};
+use clap::ArgGroup;
+use mimalloc::MiMalloc;
+use std::{ops::RangeInclusive, process::ExitCode};
+
+// Day 22 is allocation-heavy and its own binary has always used mimalloc.
+#[global_allocator]
+static GLOBAL_ALLOC: MiMalloc = MiMalloc;
+
+/// Solves any of the days of Advent of Code 2024.
+#[derive(Parser)]
+#[clap(group(ArgGroup::new("selection").required(true).args(["day", "days", "all"])))]
+struct AocCli {
+    /// Run a single day
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Run an inclusive range of days, e.g. `1-10`
+    #[clap(long, value_parser = parse_day_range, conflicts_with = "input")]
+    days: Option<RangeInclusive<u8>>,
+
+    /// Run every day
+    #[clap(short, long, conflicts_with = "input")]
+    all: bool,
+
+    #[clap(flatten)]
+    run: RunArgs,
+}
+
+fn parse_day_range(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let (start, end) = s.split_once("-").ok_or("expected a range like 1-10")?;
+    let start: u8 = start
+        .parse()
+        .map_err(|_| format!("invalid day '{}'", start))?;
+    let end: u8 = end.parse().map_err(|_| format!("invalid day '{}'", end))?;
+
+    if start < 1 || end > 25 || start > end {
+        return Err(format!("invalid day range {}-{}", start, end));
+    }
+    Ok(start..=end)
+}
+
+fn main() -> ExitCode {
+    let args = AocCli::parse();
+    args.run.threads.apply();
+
+    let days = if let Some(day) = args.day {
+        day..=day
+    } else if let Some(days) = args.days {
+        days
+    } else {
+        1..=25
+    };
+
+    let manifest = args.run.check.then(|| load_manifest(&args.run.answers));
+
+    if args.run.is_batch() {
+        let Some(day) = days.clone().find_map(registry::get) else {
+            return ExitCode::FAILURE;
+        };
+        let sources = match input::expand_args(&args.run.input) {
+            Ok(sources) => sources,
+            Err(e) => {
+                eprintln!("can't list inputs: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let batch = args.run.batch(day, manifest.as_ref());
+        return match run_batch(&batch, &sources, args.run.format) {
+            true => ExitCode::FAILURE,
+            false => ExitCode::SUCCESS,
+        };
+    }
+
+    let mut checks = Vec::new();
+    let mut failed = false;
+
+    if let Some(header) = args.run.format.header() {
+        println!("{}", header);
+    }
+
+    for day in days.filter_map(registry::get) {
+        let text = args.run.format == Format::Text;
+        if text {
+            println!("2024 Day {:02}", day.day);
+        }
+
+        let source = match args.run.input.first() {
+            Some(input) => InputSource::from_arg(input),
+            None => InputSource::for_day(&args.run.input_dir, day, args.run.example),
+        };
+        let error = match source.read() {
+            Ok(inp) => match args.run.bench.run(day, &inp, args.run.part) {
+                Ok(mut report) => {
+                    if let Some(manifest) = &manifest {
+                        let key = source.manifest_key(&args.run.input_dir);
+                        let check = manifest.check(key.as_deref(), &report, args.run.part);
+                        failed |= matches!(check, Check::Fail { .. });
+                        checks.push(check.clone());
+                        report.check = Some(check);
+                    }
+
+                    print!("{}", report.render(args.run.format));
+                    if args.run.timings && text {
+                        println!("{}", report.timings);
+                    }
+                    if let Err(e) = args.run.threads.compare(day, &inp, args.run.part, &report) {
+                        eprintln!("day {}: {}", day.day, e);
+                        failed = true;
+                    }
+                    None
+                }
+                Err(e) => Some(format!("can't parse input {}: {}", source, e)),
+            },
+            Err(e) => Some(format!("can't open input {}: {}", source, e)),
+        };
+
+        if let Some(error) = error {
+            eprintln!("{}", error);
+            failed = true;
+            // So that JSON consumers can tell which days failed.
+            if args.run.format == Format::Json {
+                println!("{}", serde_json::json!({ "day": day.day, "error": error }));
+            }
+        }
+
+        if text {
+            println!();
+        }
+    }
+
+    if args.run.check && args.run.format == Format::Text {
+        let count = |status| checks.iter().filter(|c| c.status() == status).count();
+        println!(
+            "{} passed, {} failed, {} without expected answers",
+            count("pass"),
+            count("fail"),
+            count("missing")
+        );
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
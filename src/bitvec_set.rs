@@ -1,40 +1,138 @@
+use crate::direction::Direction;
 use bitvec::prelude::*;
+use bitvec::slice::IterOnes;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-/// Emulates part of the interface of a HashSet<(usize, usize)>, with bounded
-/// allowed positions, using a bitvec as underlying storage.
-pub struct BitVecSet2D {
+/// Keys of a [`BitSet`], which map densely onto `0..capacity(bounds)`.
+pub trait BitSetKey: Copy {
+    /// What limits the keys, e.g. a grid's dimensions.
+    type Bounds: Copy + Eq + Debug;
+
+    fn capacity(bounds: Self::Bounds) -> usize;
+
+    fn in_bounds(&self, bounds: Self::Bounds) -> bool;
+
+    /// The key's bit, which only needs to be meaningful for keys in bounds.
+    fn index(&self, bounds: Self::Bounds) -> usize;
+
+    fn from_index(index: usize, bounds: Self::Bounds) -> Self;
+}
+
+impl BitSetKey for (usize, usize) {
+    type Bounds = (usize, usize);
+
+    fn capacity(bounds: (usize, usize)) -> usize {
+        bounds.0 * bounds.1
+    }
+
+    fn in_bounds(&self, bounds: (usize, usize)) -> bool {
+        self.0 < bounds.0 && self.1 < bounds.1
+    }
+
+    fn index(&self, bounds: (usize, usize)) -> usize {
+        self.0 * bounds.1 + self.1
+    }
+
+    fn from_index(index: usize, bounds: (usize, usize)) -> Self {
+        (index / bounds.1, index % bounds.1)
+    }
+}
+
+impl BitSetKey for (usize, usize, usize) {
+    type Bounds = (usize, usize, usize);
+
+    fn capacity(bounds: (usize, usize, usize)) -> usize {
+        bounds.0 * bounds.1 * bounds.2
+    }
+
+    fn in_bounds(&self, bounds: (usize, usize, usize)) -> bool {
+        self.0 < bounds.0 && self.1 < bounds.1 && self.2 < bounds.2
+    }
+
+    fn index(&self, bounds: (usize, usize, usize)) -> usize {
+        (self.0 * bounds.1 + self.1) * bounds.2 + self.2
+    }
+
+    fn from_index(index: usize, bounds: (usize, usize, usize)) -> Self {
+        (
+            index / (bounds.1 * bounds.2),
+            index / bounds.2 % bounds.1,
+            index % bounds.2,
+        )
+    }
+}
+
+/// A grid position and a direction, bounded by the grid's dimensions.
+impl BitSetKey for ((usize, usize), Direction) {
+    type Bounds = (usize, usize);
+
+    fn capacity(bounds: (usize, usize)) -> usize {
+        bounds.0 * bounds.1 * 4
+    }
+
+    fn in_bounds(&self, bounds: (usize, usize)) -> bool {
+        self.0.in_bounds(bounds)
+    }
+
+    fn index(&self, bounds: (usize, usize)) -> usize {
+        self.0.index(bounds) * 4 + self.1.index()
+    }
+
+    fn from_index(index: usize, bounds: (usize, usize)) -> Self {
+        (
+            <(usize, usize)>::from_index(index / 4, bounds),
+            Direction::ALL[index % 4],
+        )
+    }
+}
+
+/// Emulates part of the interface of a HashSet<K>, with bounded allowed keys,
+/// using a bitvec as underlying storage.
+///
+/// Set operations between two sets require them to have the same bounds, and
+/// work a word at a time. Equality only depends on the keys in the sets,
+/// whatever their bounds.
+#[derive(Clone)]
+pub struct BitSet<K: BitSetKey> {
     pub bv: BitVec,
-    pub bounds: (usize, usize),
+    pub bounds: K::Bounds,
+    key: PhantomData<K>,
 }
 
-impl BitVecSet2D {
-    pub fn new(bounds: (usize, usize)) -> BitVecSet2D {
-        BitVecSet2D {
-            bv: bitvec![usize, Lsb0; 0; bounds.0 * bounds.1],
+/// A set of grid positions.
+pub type BitVecSet2D = BitSet<(usize, usize)>;
+
+impl<K: BitSetKey> BitSet<K> {
+    pub fn new(bounds: K::Bounds) -> BitSet<K> {
+        BitSet {
+            bv: bitvec![usize, Lsb0; 0; K::capacity(bounds)],
             bounds,
+            key: PhantomData,
         }
     }
 
-    fn idx(&self, pos: &(usize, usize)) -> usize {
-        pos.0 * self.bounds.1 + pos.1
+    fn idx(&self, key: &K) -> usize {
+        debug_assert!(key.in_bounds(self.bounds));
+        key.index(self.bounds)
     }
 
-    pub fn get(&self, pos: &(usize, usize)) -> Option<bool> {
-        if pos.0 >= self.bounds.0 || pos.1 >= self.bounds.1 {
-            None
+    pub fn get(&self, key: &K) -> Option<bool> {
+        if key.in_bounds(self.bounds) {
+            self.bv.get(self.idx(key)).map(|b| *b)
         } else {
-            self.bv.get(self.idx(pos)).map(|b| *b)
+            None
         }
     }
 
-    pub fn contains(&self, pos: &(usize, usize)) -> bool {
-        self.get(pos).is_some_and(|b| b)
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some_and(|b| b)
     }
 
-    pub fn insert(&mut self, pos: (usize, usize)) -> bool {
-        debug_assert!(pos.0 < self.bounds.0);
-        debug_assert!(pos.1 < self.bounds.1);
-        let idx = self.idx(&pos);
+    pub fn insert(&mut self, key: K) -> bool {
+        let idx = self.idx(&key);
         !self
             .bv
             .get_mut(idx)
@@ -42,10 +140,8 @@ impl BitVecSet2D {
             .replace(true)
     }
 
-    pub fn remove(&mut self, pos: (usize, usize)) -> bool {
-        debug_assert!(pos.0 < self.bounds.0);
-        debug_assert!(pos.1 < self.bounds.1);
-        let idx = self.idx(&pos);
+    pub fn remove(&mut self, key: K) -> bool {
+        let idx = self.idx(&key);
         self.bv
             .get_mut(idx)
             .expect("invalid bv index")
@@ -64,9 +160,282 @@ impl BitVecSet2D {
         self.bv.fill(false);
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + use<'_> {
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter {
+            ones: self.bv.iter_ones(),
+            bounds: self.bounds,
+        }
+    }
+
+    fn combine_with(&mut self, other: &BitSet<K>, op: impl Fn(usize, usize) -> usize) {
+        assert_eq!(self.bounds, other.bounds, "sets have different bounds");
         self.bv
-            .iter_ones()
-            .map(|b| (b / self.bounds.1, b % self.bounds.1))
+            .as_raw_mut_slice()
+            .iter_mut()
+            .zip(other.bv.as_raw_slice())
+            .for_each(|(a, &b)| *a = op(*a, b));
+    }
+
+    /// Adds every key in `other`.
+    pub fn union_with(&mut self, other: &BitSet<K>) {
+        self.combine_with(other, |a, b| a | b);
+    }
+
+    /// Keeps only keys also in `other`.
+    pub fn intersect_with(&mut self, other: &BitSet<K>) {
+        self.combine_with(other, |a, b| a & b);
+    }
+
+    /// Removes every key in `other`.
+    pub fn difference_with(&mut self, other: &BitSet<K>) {
+        self.combine_with(other, |a, b| a & !b);
+    }
+
+    /// Keeps keys in exactly one of `self` and `other`.
+    pub fn symmetric_difference_with(&mut self, other: &BitSet<K>) {
+        self.combine_with(other, |a, b| a ^ b);
+    }
+}
+
+macro_rules! set_op {
+    ($trait:ident, $method:ident, $with:ident) => {
+        impl<K: BitSetKey> $trait for &BitSet<K> {
+            type Output = BitSet<K>;
+
+            fn $method(self, other: &BitSet<K>) -> BitSet<K> {
+                let mut result = self.clone();
+                result.$with(other);
+                result
+            }
+        }
+    };
+}
+
+set_op!(BitOr, bitor, union_with);
+set_op!(BitAnd, bitand, intersect_with);
+set_op!(Sub, sub, difference_with);
+set_op!(BitXor, bitxor, symmetric_difference_with);
+
+/// Iterates over a [`BitSet`] in order of key index.
+pub struct Iter<'a, K: BitSetKey> {
+    ones: IterOnes<'a, usize, Lsb0>,
+    bounds: K::Bounds,
+}
+
+impl<K: BitSetKey> Iterator for Iter<'_, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        self.ones.next().map(|b| K::from_index(b, self.bounds))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ones.size_hint()
+    }
+}
+
+impl<'a, K: BitSetKey> IntoIterator for &'a BitSet<K> {
+    type Item = K;
+    type IntoIter = Iter<'a, K>;
+
+    fn into_iter(self) -> Iter<'a, K> {
+        self.iter()
+    }
+}
+
+/// Iterates over an owned [`BitSet`] in order of key index.
+pub struct IntoIter<K: BitSetKey> {
+    set: BitSet<K>,
+    next: usize,
+}
+
+impl<K: BitSetKey> Iterator for IntoIter<K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        let b = self.next + self.set.bv.get(self.next..)?.first_one()?;
+        self.next = b + 1;
+        Some(K::from_index(b, self.set.bounds))
+    }
+}
+
+impl<K: BitSetKey> IntoIterator for BitSet<K> {
+    type Item = K;
+    type IntoIter = IntoIter<K>;
+
+    fn into_iter(self) -> IntoIter<K> {
+        IntoIter { set: self, next: 0 }
+    }
+}
+
+impl<K: BitSetKey> Extend<K> for BitSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|key| {
+            self.insert(key);
+        });
+    }
+}
+
+impl FromIterator<(usize, usize)> for BitVecSet2D {
+    /// Collects into a set just big enough to hold every position. Prefer
+    /// [`BitVecSet2D::new`] and [`Extend`] where the bounds are known, as
+    /// set operations need matching bounds.
+    fn from_iter<I: IntoIterator<Item = (usize, usize)>>(iter: I) -> BitVecSet2D {
+        let positions = iter.into_iter().collect::<Vec<_>>();
+        let bounds = positions
+            .iter()
+            .fold((0, 0), |(r, c), pos| (r.max(pos.0 + 1), c.max(pos.1 + 1)));
+
+        let mut set = BitVecSet2D::new(bounds);
+        set.extend(positions);
+        set
+    }
+}
+
+impl<K: BitSetKey> PartialEq for BitSet<K> {
+    fn eq(&self, other: &BitSet<K>) -> bool {
+        if self.bounds == other.bounds {
+            return self.bv == other.bv;
+        }
+        self.len() == other.len() && self.iter().all(|key| other.contains(&key))
+    }
+}
+
+impl<K: BitSetKey> Eq for BitSet<K> {}
+
+impl<K: BitSetKey + Debug> Debug for BitSet<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A [`BitSet`] that can be shared between threads, e.g. as the visited set of
+/// a rayon flood fill. Only supports adding keys, and lock-free.
+pub struct AtomicBitSet<K: BitSetKey> {
+    words: Vec<AtomicUsize>,
+    pub bounds: K::Bounds,
+    key: PhantomData<K>,
+}
+
+/// A set of grid positions that can be shared between threads.
+pub type AtomicBitSet2D = AtomicBitSet<(usize, usize)>;
+
+impl<K: BitSetKey> AtomicBitSet<K> {
+    pub fn new(bounds: K::Bounds) -> AtomicBitSet<K> {
+        AtomicBitSet {
+            words: (0..K::capacity(bounds).div_ceil(usize::BITS as usize))
+                .map(|_| AtomicUsize::new(0))
+                .collect(),
+            bounds,
+            key: PhantomData,
+        }
+    }
+
+    fn word_and_mask(&self, key: &K) -> (&AtomicUsize, usize) {
+        debug_assert!(key.in_bounds(self.bounds));
+        let idx = key.index(self.bounds);
+        (
+            &self.words[idx / usize::BITS as usize],
+            1 << (idx % usize::BITS as usize),
+        )
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        key.in_bounds(self.bounds) && {
+            let (word, mask) = self.word_and_mask(key);
+            word.load(Ordering::Relaxed) & mask != 0
+        }
+    }
+
+    /// Adds `key`, returning whether it was newly added. Exactly one of any
+    /// number of concurrent inserts of the same key returns true.
+    pub fn insert(&self, key: K) -> bool {
+        let (word, mask) = self.word_and_mask(&key);
+        word.fetch_or(mask, Ordering::Relaxed) & mask == 0
+    }
+
+    /// Converts into an ordinary set, once all threads are done with it.
+    pub fn freeze(self) -> BitSet<K> {
+        let mut bv = BitVec::from_vec(
+            self.words
+                .into_iter()
+                .map(AtomicUsize::into_inner)
+                .collect(),
+        );
+        bv.truncate(K::capacity(self.bounds));
+
+        BitSet {
+            bv,
+            bounds: self.bounds,
+            key: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    fn set(positions: &[(usize, usize)]) -> BitVecSet2D {
+        let mut set = BitVecSet2D::new((3, 40));
+        set.extend(positions.iter().copied());
+        set
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a = set(&[(0, 0), (1, 39), (2, 5)]);
+        let b = set(&[(1, 39), (2, 6)]);
+
+        assert_eq!(&a | &b, set(&[(0, 0), (1, 39), (2, 5), (2, 6)]));
+        assert_eq!(&a & &b, set(&[(1, 39)]));
+        assert_eq!(&a - &b, set(&[(0, 0), (2, 5)]));
+        assert_eq!(&a ^ &b, set(&[(0, 0), (2, 5), (2, 6)]));
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut states = BitSet::<((usize, usize), Direction)>::new((3, 3));
+        assert!(states.insert(((2, 1), Direction::Left)));
+        assert!(!states.insert(((2, 1), Direction::Left)));
+        assert!(!states.contains(&((2, 1), Direction::Up)));
+        assert_eq!(states.get(&((3, 0), Direction::Up)), None);
+        assert_eq!(
+            states.iter().collect::<Vec<_>>(),
+            [((2, 1), Direction::Left)]
+        );
+
+        let mut cube = BitSet::<(usize, usize, usize)>::new((2, 3, 4));
+        cube.extend([(1, 2, 3), (0, 1, 2)]);
+        assert_eq!(cube.into_iter().collect::<Vec<_>>(), [(0, 1, 2), (1, 2, 3)]);
+    }
+
+    #[test]
+    fn test_collections() {
+        let a = [(2, 1), (0, 3)].into_iter().collect::<BitVecSet2D>();
+        assert_eq!(a.bounds, (3, 4));
+        assert_eq!(format!("{:?}", a), "{(0, 3), (2, 1)}");
+        assert_eq!((&a).into_iter().count(), 2);
+        assert_eq!(a, set(&[(0, 3), (2, 1)]));
+        assert_ne!(a, set(&[(0, 3)]));
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), [(0, 3), (2, 1)]);
+    }
+
+    #[test]
+    fn test_atomic() {
+        let set = AtomicBitSet2D::new((10, 13));
+        let newly_added = (0..1000)
+            .into_par_iter()
+            .filter(|i| set.insert((i % 10, i % 13)))
+            .count();
+
+        assert_eq!(newly_added, 130);
+        assert!(set.contains(&(9, 12)));
+        assert!(!set.contains(&(10, 0)));
+
+        let frozen = set.freeze();
+        assert_eq!(frozen.len(), 130);
+        assert_eq!(frozen.bv.len(), 130);
     }
 }
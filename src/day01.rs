@@ -0,0 +1,80 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use ahash::AHashMap;
+use itertools::Itertools;
+use std::iter::zip;
+
+/// The two location ID lists, each sorted.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub left: Vec<i32>,
+    pub right: Vec<i32>,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = Input;
+    type P1 = i32;
+    type P2 = i32;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (mut left, mut right): (Vec<i32>, Vec<i32>) = raw_inp
+            .lines()
+            .map(|line| {
+                let (l, r) = split_once(raw_inp, line, "   ")?;
+                Ok((parse_num::<i32>(raw_inp, l)?, parse_num::<i32>(raw_inp, r)?))
+            })
+            .process_results(|iter| iter.unzip())?;
+
+        left.sort_unstable();
+        right.sort_unstable();
+
+        Ok(Input { left, right })
+    }
+
+    fn part1(Input { left, right }: &Self::Parsed<'_>) -> i32 {
+        zip(left, right).map(|(l, r)| (l - r).abs()).sum()
+    }
+
+    fn part2(Input { left, right }: &Self::Parsed<'_>) -> i32 {
+        let right_count: AHashMap<i32, i32> = right
+            .iter()
+            .dedup_with_count()
+            .map(|(count, v)| (*v, count as i32))
+            .collect();
+
+        left.iter()
+            .map(|l| l * right_count.get(l).unwrap_or(&0))
+            .sum()
+    }
+}
+
+solution_fns!(Day01);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day01>(EXAMPLE_DATA).unwrap(), (11, 31));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day01>();
+    }
+}
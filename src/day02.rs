@@ -0,0 +1,99 @@
+use crate::parse_util::parse_num;
+use crate::{ParseError, Solution};
+use itertools::Itertools;
+
+fn is_safe(nums: &[i32]) -> bool {
+    // Too few levels to go the wrong way, as when removing one of two.
+    let [first, second, ..] = nums else {
+        return true;
+    };
+    let first_dir = second > first;
+
+    nums.iter()
+        .tuple_windows()
+        .all(|(a, b)| (1..=3).contains(&(a - b).abs()) && ((b > a) == first_dir))
+}
+
+/// The reports, each a list of at least two levels.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub reports: Vec<Vec<i32>>,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let reports = raw_inp
+            .lines()
+            .map(|line| {
+                let report = line
+                    .split(" ")
+                    .map(|n| parse_num(raw_inp, n))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if report.len() < 2 {
+                    return Err(ParseError::new(raw_inp, line, "expected at least 2 levels"));
+                }
+                Ok(report)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { reports })
+    }
+
+    fn part1(Input { reports }: &Self::Parsed<'_>) -> usize {
+        reports.iter().filter(|r| is_safe(r)).count()
+    }
+
+    fn part2(Input { reports }: &Self::Parsed<'_>) -> usize {
+        reports
+            .iter()
+            .filter(|r| {
+                is_safe(r)
+                    || r.iter()
+                        .cloned()
+                        .combinations(r.len() - 1)
+                        .any(|sr| is_safe(&sr))
+            })
+            .count()
+    }
+}
+
+solution_fns!(Day02);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day02>(EXAMPLE_DATA).unwrap(), (2, 4));
+    }
+
+    #[test]
+    fn test_two_levels() {
+        assert_eq!(solve::<Day02>("1 9\n").unwrap(), (0, 1));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day02>();
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Do,
+    Dont,
+    Mul(i32, i32),
+}
+
+fn try_parse_mul(raw_inp: &str) -> Option<(i32, i32)> {
+    if !raw_inp.starts_with("mul(") {
+        return None;
+    }
+    if let Some((a, tail)) = raw_inp[4..].split_once(",") {
+        if let Ok(a) = a.parse() {
+            if let Some((b, _)) = tail.split_once(")") {
+                if let Ok(b) = b.parse() {
+                    return Some((a, b));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// The uncorrupted instructions, in order.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub instructions: Vec<Instruction>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Input;
+    type P1 = i32;
+    type P2 = i32;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let instructions = (0..raw_inp.len())
+            .filter(|&start| raw_inp.is_char_boundary(start))
+            .filter_map(|start| {
+                if raw_inp[start..].starts_with("do()") {
+                    Some(Instruction::Do)
+                } else if raw_inp[start..].starts_with("don't()") {
+                    Some(Instruction::Dont)
+                } else {
+                    try_parse_mul(&raw_inp[start..]).map(|(a, b)| Instruction::Mul(a, b))
+                }
+            })
+            .collect();
+
+        Ok(Input { instructions })
+    }
+
+    fn part1(Input { instructions }: &Self::Parsed<'_>) -> i32 {
+        instructions
+            .iter()
+            .map(|inst| match inst {
+                Instruction::Mul(a, b) => a * b,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn part2(Input { instructions }: &Self::Parsed<'_>) -> i32 {
+        let mut enabled: bool = true;
+        let mut p2 = 0;
+
+        for inst in instructions {
+            match inst {
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                Instruction::Mul(a, b) => {
+                    if enabled {
+                        p2 += a * b;
+                    }
+                }
+            }
+        }
+        p2
+    }
+}
+
+solution_fns!(Day03);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA_P1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+    const EXAMPLE_DATA_P2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(solve::<Day03>("").unwrap(), (0, 0));
+        assert_eq!(solve::<Day03>("mul(2,3").unwrap(), (0, 0));
+        assert_eq!(solve::<Day03>("mul(2,3)").unwrap(), (6, 6));
+        assert_eq!(solve::<Day03>("don't()mul(2,3)").unwrap(), (6, 0));
+        assert_eq!(solve::<Day03>("mul(2,3)don't()mul(2,3)").unwrap(), (12, 6));
+    }
+
+    #[test]
+    fn test_example_p1() {
+        assert_eq!(solve::<Day03>(EXAMPLE_DATA_P1).unwrap().0, 161);
+    }
+
+    #[test]
+    fn test_example_p2() {
+        assert_eq!(solve::<Day03>(EXAMPLE_DATA_P2).unwrap().1, 48);
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day03>();
+    }
+}
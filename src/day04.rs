@@ -0,0 +1,105 @@
+use crate::{grid_util::make_byte_grid, ParseError, Solution};
+use ndarray::{indices_of, Array2};
+
+fn calculate_p1(grid: &Array2<u8>) -> usize {
+    indices_of(grid)
+        .into_iter()
+        .map(|(r, c)| {
+            let mut count = 0;
+            for s in [b"XMAS", b"SAMX"] {
+                // Right
+                if (0..=3).all(|i| grid.get((r, c + i)) == Some(&s[i])) {
+                    count += 1;
+                }
+                // Down
+                if (0..=3).all(|i| grid.get((r + i, c)) == Some(&s[i])) {
+                    count += 1;
+                }
+                // Diagonal down-right
+                if (0..=3).all(|i| grid.get((r + i, c + i)) == Some(&s[i])) {
+                    count += 1;
+                }
+                // Diagonal down-left
+                if (0..=3).all(|i| grid.get((r + i, c.wrapping_sub(i))) == Some(&s[i])) {
+                    count += 1;
+                }
+            }
+            count
+        })
+        .sum()
+}
+
+fn calculate_p2(grid: &Array2<u8>) -> usize {
+    grid.indexed_iter()
+        .filter(|(_, &val)| val == b'A')
+        .filter(|((r, c), _)| {
+            [b"MSMS", b"MSSM", b"SMMS", b"SMSM"].iter().any(|&s| {
+                grid.get((r.wrapping_sub(1), c.wrapping_sub(1))) == Some(&s[0])
+                    && grid.get((r + 1, c + 1)) == Some(&s[1])
+                    && grid.get((r + 1, c.wrapping_sub(1))) == Some(&s[2])
+                    && grid.get((r.wrapping_sub(1), c + 1)) == Some(&s[3])
+            })
+        })
+        .count()
+}
+
+/// The word search.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Array2<u8>,
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Input {
+            grid: make_byte_grid(raw_inp)?,
+        })
+    }
+
+    fn part1(Input { grid }: &Self::Parsed<'_>) -> usize {
+        calculate_p1(grid)
+    }
+
+    fn part2(Input { grid }: &Self::Parsed<'_>) -> usize {
+        calculate_p2(grid)
+    }
+}
+
+solution_fns!(Day04);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day04>(EXAMPLE_DATA).unwrap(), (18, 9));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day04>();
+    }
+}
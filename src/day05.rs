@@ -0,0 +1,161 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use ahash::AHashMap;
+use std::sync::OnceLock;
+
+fn score_middle(pages: &[i32]) -> i32 {
+    pages[pages.len() / 2]
+}
+
+fn scores_of_update(update: &[i32], rules: &AHashMap<i32, Vec<i32>>) -> (i32, i32) {
+    let mut items = update.to_vec();
+
+    let mut pages = Vec::with_capacity(items.len());
+    let mut was_valid_p1 = true;
+
+    while !items.is_empty() {
+        let item = *items
+            .iter()
+            .find(|&&i| {
+                rules
+                    .get(&i)
+                    .unwrap_or(&Vec::<i32>::default())
+                    .iter()
+                    .filter(|dep| items.contains(dep))
+                    .all(|dep| pages.contains(dep))
+            })
+            .expect("impossible dependencies");
+
+        if item != items[0] {
+            was_valid_p1 = false;
+        }
+
+        pages.push(item);
+        items.retain(|i| i != &item);
+    }
+
+    if was_valid_p1 {
+        (score_middle(&pages), 0)
+    } else {
+        (0, score_middle(&pages))
+    }
+}
+
+/// The page ordering rules and the updates to check against them.
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// The pages that must come before each page.
+    pub rules: AHashMap<i32, Vec<i32>>,
+    pub updates: Vec<Vec<i32>>,
+    scores: OnceLock<(i32, i32)>,
+}
+
+impl Input {
+    /// The sums of the middle pages of the updates already in order, and of
+    /// the others once ordered, worked out once by whichever part needs them
+    /// first.
+    pub fn scores(&self) -> (i32, i32) {
+        *self.scores.get_or_init(|| {
+            self.updates
+                .iter()
+                .map(|update| scores_of_update(update, &self.rules))
+                .fold((0, 0), |(p1, p2), (a, b)| (p1 + a, p2 + b))
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Input;
+    type P1 = i32;
+    type P2 = i32;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (rules, pages) = split_once(raw_inp, raw_inp, "\n\n")?;
+
+        let mut rulesmap = AHashMap::<i32, Vec<i32>>::default();
+
+        for line in rules.lines() {
+            let (x, y) = split_once(raw_inp, line, "|")?;
+            rulesmap
+                .entry(parse_num(raw_inp, y)?)
+                .or_insert(vec![])
+                .push(parse_num(raw_inp, x)?);
+        }
+
+        let updates = pages
+            .lines()
+            .map(|line| {
+                line.split(",")
+                    .map(|val| parse_num(raw_inp, val))
+                    .collect::<Result<Vec<i32>, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input {
+            rules: rulesmap,
+            updates,
+            scores: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> i32 {
+        input.scores().0
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> i32 {
+        input.scores().1
+    }
+}
+
+solution_fns!(Day05);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day05>(EXAMPLE_DATA).unwrap(), (143, 123));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day05>();
+    }
+}
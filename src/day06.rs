@@ -0,0 +1,197 @@
+use crate::bitvec_set::{BitSet, BitVecSet2D};
+use crate::direction::Direction;
+use crate::grid_util::{make_grid_with_markers, Grid};
+use crate::{ParseError, Solution};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use std::sync::OnceLock;
+
+type JumpMap = FxHashMap<(usize, usize, Direction), (usize, usize, Direction)>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Open,
+    Obstacle,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(b: u8) -> Result<Tile, u8> {
+        match b {
+            b'.' => Ok(Tile::Open),
+            b'#' => Ok(Tile::Obstacle),
+            _ => Err(b),
+        }
+    }
+}
+
+fn next_pos(
+    grid: &Grid<Tile>,
+    pos: (usize, usize),
+    dir: Direction,
+    extra_obstacle: Option<(usize, usize)>,
+    jump_map: &JumpMap,
+) -> ((usize, usize), Direction) {
+    if extra_obstacle.map(|f| f.0) != Some(pos.0) && extra_obstacle.map(|f| f.1) != Some(pos.1) {
+        if let Some((y, x, d)) = jump_map.get(&(pos.0, pos.1, dir)) {
+            return ((*y, *x), *d);
+        }
+    }
+
+    let want = dir.step(pos);
+    if Some(want) != extra_obstacle && grid.get(want) != Some(&Tile::Obstacle) {
+        (want, dir)
+    } else {
+        (pos, dir.turn_right())
+    }
+}
+
+fn does_loop(
+    grid: &Grid<Tile>,
+    start_pos: (usize, usize),
+    extra_obstacle: (usize, usize),
+    jump_map: &JumpMap,
+) -> bool {
+    // Store visited states in a bitset, this is ~5x faster than a HashSet.
+    let mut visited = BitSet::<((usize, usize), Direction)>::new(grid.dim());
+
+    let mut pos = start_pos;
+    let mut dir: Direction = Direction::Up;
+
+    let dim_y = grid.dim().0;
+    let dim_x = grid.dim().1;
+
+    while pos.0 < dim_y && pos.1 < dim_x {
+        if !visited.insert((pos, dir)) {
+            return true;
+        }
+        (pos, dir) = next_pos(grid, pos, dir, Some(extra_obstacle), jump_map);
+    }
+    false
+}
+
+fn make_jump_map(grid: &Grid<Tile>) -> JumpMap {
+    grid.indexed_iter()
+        .filter(|(_, &v)| v == Tile::Obstacle)
+        .flat_map(|(pos, _)| {
+            // The tiles from which moving in `d` walks into this obstacle
+            Direction::ALL.map(|d| {
+                let (y, x) = d.reverse().step(pos);
+                (y, x, d)
+            })
+        })
+        .filter(|(y, x, _)| y < &grid.dim().0 && x < &grid.dim().1)
+        .map(|(y, x, d)| {
+            let nd = d.turn_right();
+            let mut np = (y, x);
+
+            while grid.get(nd.step(np)) == Some(&Tile::Open) {
+                np = nd.step(np);
+            }
+            ((y, x, d), (np.0, np.1, nd))
+        })
+        .collect()
+}
+
+fn visited_positions(grid: &Grid<Tile>, start_pos: (usize, usize)) -> BitVecSet2D {
+    let mut visited = BitVecSet2D::new(grid.dim());
+
+    let mut pos = start_pos;
+    let mut dir = Direction::Up;
+
+    let dim_y = grid.dim().0;
+    let dim_x = grid.dim().1;
+
+    while pos.0 < dim_y && pos.1 < dim_x {
+        visited.insert(pos);
+        (pos, dir) = next_pos(grid, pos, dir, None, &FxHashMap::default());
+    }
+
+    visited
+}
+
+/// The lab map and where the guard starts, facing up.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Grid<Tile>,
+    pub start: (usize, usize),
+    visited: OnceLock<BitVecSet2D>,
+}
+
+impl Input {
+    /// Every position the guard visits before leaving, worked out once by
+    /// whichever part needs it first.
+    pub fn visited(&self) -> &BitVecSet2D {
+        self.visited
+            .get_or_init(|| visited_positions(&self.grid, self.start))
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (grid, [start]) = make_grid_with_markers(raw_inp, [b'^'], b'.')?;
+        Ok(Input {
+            grid,
+            start,
+            visited: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> usize {
+        input.visited().len()
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> usize {
+        let Input { grid, start, .. } = input;
+        let jump_map = make_jump_map(grid);
+
+        input
+            .visited()
+            .iter()
+            .collect::<Vec<_>>()
+            .par_iter()
+            .filter(|&pos| pos != start)
+            .filter(|&obstacle| does_loop(grid, *start, *obstacle, &jump_map))
+            .count()
+    }
+}
+
+solution_fns!(Day06);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day06>(EXAMPLE_DATA).unwrap(), (41, 6));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day06>();
+    }
+}
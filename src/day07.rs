@@ -0,0 +1,130 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use num_integer::div_rem;
+
+fn can_match<const ALLOW_COMBINATION: bool>(result: i64, nums: &[i64]) -> bool {
+    if let Some(&lastnum) = nums.last() {
+        debug_assert!(lastnum > 0);
+        let a = &nums[0..nums.len() - 1];
+
+        if ALLOW_COMBINATION {
+            let (d, m) = div_rem(result - lastnum, 10i64.pow(lastnum.ilog10() + 1));
+            if m == 0 && can_match::<ALLOW_COMBINATION>(d, a) {
+                return true;
+            }
+        }
+
+        let (d, m) = div_rem(result, lastnum);
+        if m == 0 && can_match::<ALLOW_COMBINATION>(d, a) {
+            return true;
+        }
+
+        if can_match::<ALLOW_COMBINATION>(result - lastnum, a) {
+            return true;
+        }
+        false
+    } else {
+        result == 0
+    }
+}
+
+/// The calibration equations, as each test value and its operands.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub equations: Vec<(i64, Vec<i64>)>,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = Input;
+    type P1 = i64;
+    type P2 = i64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let equations = raw_inp
+            .lines()
+            .map(|line| {
+                let (head, tail) = split_once(raw_inp, line, ": ")?;
+
+                let result = parse_num(raw_inp, head)?;
+                let nums = tail
+                    .split(" ")
+                    .map(|n| match parse_num(raw_inp, n)? {
+                        n if n > 0 => Ok(n),
+                        _ => Err(ParseError::new(raw_inp, n, "expected a positive number")),
+                    })
+                    .collect::<Result<Vec<i64>, _>>()?;
+
+                Ok((result, nums))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { equations })
+    }
+
+    fn part1(Input { equations }: &Self::Parsed<'_>) -> i64 {
+        equations
+            .iter()
+            .filter(|(result, nums)| can_match::<false>(*result, nums))
+            .map(|(result, _)| result)
+            .sum()
+    }
+
+    fn part2(Input { equations }: &Self::Parsed<'_>) -> i64 {
+        equations
+            .iter()
+            .filter(|(result, nums)| {
+                can_match::<false>(*result, nums) || can_match::<true>(*result, nums)
+            })
+            .map(|(result, _)| result)
+            .sum()
+    }
+}
+
+solution_fns!(Day07);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day07>(EXAMPLE_DATA).unwrap(), (3749, 11387));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day07>();
+    }
+
+    #[test]
+    fn test_combination() {
+        assert!(can_match::<true>(15, &[1, 5]));
+        assert!(can_match::<true>(105, &[10, 5]));
+        assert!(can_match::<true>(1005, &[100, 5]));
+        assert!(can_match::<true>(5100, &[5, 100]));
+        assert!(can_match::<true>(9999, &[99, 99]));
+
+        assert!(!can_match::<false>(15, &[1, 5]));
+        assert!(!can_match::<false>(105, &[10, 5]));
+        assert!(!can_match::<false>(1005, &[100, 5]));
+        assert!(!can_match::<false>(5100, &[5, 100]));
+        assert!(!can_match::<false>(9999, &[99, 99]));
+    }
+}
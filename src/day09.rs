@@ -0,0 +1,149 @@
+use crate::{ParseError, Solution};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy)]
+struct AmphipodFile {
+    id: usize,
+    len: usize,
+    offset: usize,
+}
+
+fn sum_to_n(n: usize) -> usize {
+    (n * (n + 1)) / 2
+}
+
+/// The disk map, alternating between file and free space lengths.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub lengths: Vec<usize>,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let digits = raw_inp.trim();
+
+        let lengths = digits
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                    ParseError::new(raw_inp, &digits[i..i + c.len_utf8()], "expected a digit")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { lengths })
+    }
+
+    fn part1(Input { lengths }: &Self::Parsed<'_>) -> usize {
+        let mut is_file = true;
+        let mut id = 0_usize;
+
+        let mut disk = lengths
+            .iter()
+            .flat_map(|&len| {
+                let r = if is_file {
+                    [Some(id)].repeat(len)
+                } else {
+                    [None].repeat(len)
+                };
+                if is_file {
+                    id += 1;
+                }
+                is_file = !is_file;
+                r
+            })
+            .collect::<Vec<Option<usize>>>();
+
+        let mut next_empty_slot = disk.iter().position(|&e| e.is_none()).unwrap();
+        while next_empty_slot < disk.len() {
+            disk.swap_remove(next_empty_slot);
+
+            while next_empty_slot < disk.len() && disk[next_empty_slot].is_some() {
+                next_empty_slot += 1;
+            }
+        }
+
+        disk.into_iter()
+            .zip(0_usize..)
+            .map(|(x, y)| x.unwrap_or(0) * y)
+            .sum()
+    }
+
+    fn part2(Input { lengths }: &Self::Parsed<'_>) -> usize {
+        let mut is_file = true;
+        let mut id = 0_usize;
+
+        let mut offset = 0_usize;
+
+        let mut space_buckets: [VecDeque<usize>; 10] = Default::default();
+        let mut files = vec![];
+
+        lengths.iter().for_each(|&len| {
+            if is_file {
+                files.push(AmphipodFile { id, len, offset });
+                id += 1;
+            } else {
+                space_buckets[len].push_back(offset);
+            }
+            is_file = !is_file;
+            offset += len;
+        });
+
+        for file in files.iter_mut().rev() {
+            if let Some(bucket) = (file.len..space_buckets.len())
+                .map(|i| (i, &space_buckets[i]))
+                .filter(|(_, b)| b.front().map(|x| x < &file.offset).unwrap_or(false))
+                .min_by(|a, b| a.1[0].cmp(&b.1[0]))
+                .map(|x| x.0)
+            {
+                let space = space_buckets[bucket].pop_front().expect("empty bucket");
+
+                file.offset = space;
+
+                if file.len < bucket {
+                    let itm = space + file.len;
+                    let idx = match space_buckets[bucket - file.len].binary_search(&itm) {
+                        Ok(i) => i,
+                        Err(i) => i,
+                    };
+                    space_buckets[bucket - file.len].insert(idx, itm);
+                }
+            }
+        }
+
+        files
+            .into_iter()
+            .map(|f| f.id * (sum_to_n(f.len) + f.len * f.offset - f.len))
+            .sum()
+    }
+}
+
+solution_fns!(Day09);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "2333133121414131402
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day09>(EXAMPLE_DATA).unwrap(), (1928, 2858));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day09>();
+    }
+}
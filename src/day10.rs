@@ -0,0 +1,214 @@
+use crate::grid_util::{make_grid, Grid};
+use crate::{ParseError, Solution};
+use bitvec::prelude::*;
+use ndarray::Array2;
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Height(u8);
+
+// Never one more than any height, so can't be walked onto.
+const IMPASSABLE: Height = Height(u8::MAX);
+
+impl TryFrom<u8> for Height {
+    type Error = u8;
+
+    fn try_from(b: u8) -> Result<Height, u8> {
+        match b {
+            b'0'..=b'9' => Ok(Height(b - b'0')),
+            b'.' | b'@' => Ok(IMPASSABLE),
+            _ => Err(b),
+        }
+    }
+}
+
+fn reachable(data: &Grid<Height>, start_at: (usize, usize)) -> (usize, usize) {
+    let mut q = VecDeque::new();
+    q.push_back(start_at);
+
+    let mut ways = Array2::from_elem(data.dim(), 0);
+    let mut seen = bitvec![u32, Lsb0; 0; data.dim().0 * data.dim().1];
+
+    ways[start_at] = 1;
+
+    let mut p1 = 0;
+    let mut p2 = 0;
+
+    while let Some(pos) = q.pop_front() {
+        let Height(this_tile) = data[pos];
+        if seen
+            .get_mut(pos.0 * data.dim().1 + pos.1)
+            .unwrap()
+            .replace(true)
+        {
+            continue;
+        }
+
+        if this_tile == 9 {
+            p1 += 1;
+            p2 += ways[pos];
+            continue;
+        }
+
+        for next_pos in data.neighbours4(pos) {
+            if data[next_pos].0 == this_tile + 1 {
+                ways[next_pos] += ways[pos];
+                q.push_back(next_pos);
+            }
+        }
+    }
+
+    (p1, p2)
+}
+
+/// The topographic map.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Grid<Height>,
+    scores: OnceLock<(usize, usize)>,
+}
+
+impl Input {
+    /// The sums of the trailheads' scores and of their ratings, worked out
+    /// once by whichever part needs them first.
+    pub fn scores(&self) -> (usize, usize) {
+        *self.scores.get_or_init(|| {
+            self.grid
+                .positions(|&v| v == Height(0))
+                .map(|idx| reachable(&self.grid, idx))
+                .fold((0, 0), |(p1, p2), (a, b)| (p1 + a, p2 + b))
+        })
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Input {
+            grid: make_grid(raw_inp)?,
+            scores: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> usize {
+        input.scores().0
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> usize {
+        input.scores().1
+    }
+}
+
+solution_fns!(Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    const P1_SIMPLE_EX_1: &str = "@@@0@@@
+@@@1@@@
+@@@2@@@
+6543456
+7@@@@@7
+8@@@@@8
+9@@@@@9";
+
+    const P1_SIMPLE_EX_2: &str = "@@90@@9
+@@@1@98
+@@@2@@7
+6543456
+765@987
+876@@@@
+987@@@@";
+
+    const P1_SIMPLE_EX_3: &str = "10@@9@@
+2@@@8@@
+3@@@7@@
+4567654
+@@@8@@3
+@@@9@@2
+@@@@@01";
+
+    const P2_SIMPLE_EX_1: &str = "@@@@@0@
+@@4321@
+@@5@@2@
+@@6543@
+@@7@@4@
+@@8765@
+@@9@@@@";
+
+    const P2_SIMPLE_EX_2: &str = "@@90@@9
+@@@1@98
+@@@2@@7
+6543456
+765@987
+876@@@@
+987@@@@";
+
+    const P2_SIMPLE_EX_3: &str = "012345
+123456
+234567
+345678
+4@6789
+56789@";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day10>(EXAMPLE_DATA).unwrap(), (36, 81));
+    }
+
+    #[test]
+    fn test_p1_simple_example_1() {
+        assert_eq!(solve::<Day10>(P1_SIMPLE_EX_1).unwrap().0, 2);
+    }
+
+    #[test]
+    fn test_p1_simple_example_2() {
+        assert_eq!(solve::<Day10>(P1_SIMPLE_EX_2).unwrap().0, 4);
+    }
+
+    #[test]
+    fn test_p1_simple_example_3() {
+        assert_eq!(solve::<Day10>(P1_SIMPLE_EX_3).unwrap().0, 3);
+    }
+
+    #[test]
+    fn test_p2_simple_example_1() {
+        assert_eq!(solve::<Day10>(P2_SIMPLE_EX_1).unwrap().1, 3);
+    }
+
+    #[test]
+    fn test_p2_simple_example_2() {
+        assert_eq!(solve::<Day10>(P2_SIMPLE_EX_2).unwrap().1, 13);
+    }
+
+    #[test]
+    fn test_p2_simple_example_3() {
+        assert_eq!(solve::<Day10>(P2_SIMPLE_EX_3).unwrap().1, 227);
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day10>();
+    }
+}
@@ -0,0 +1,117 @@
+use crate::parse_util::parse_num;
+use crate::{ParseError, Solution};
+use num_integer::div_rem;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+fn split_num(n: u64) -> Option<(u64, u64)> {
+    let a = n.ilog10() + 1;
+
+    if a.is_multiple_of(2) {
+        Some(div_rem(n, 10_u64.pow(a / 2)))
+    } else {
+        None
+    }
+}
+
+fn blink<const BLINKS: usize>(stones: &FxHashMap<u64, usize>) -> usize {
+    let mut stones = stones.clone();
+
+    for _ in 0..BLINKS {
+        let cap = stones.capacity();
+        stones = stones.into_iter().fold(
+            FxHashMap::with_capacity_and_hasher(cap, FxBuildHasher),
+            |mut m, (n, v)| {
+                if n == 0 {
+                    *m.entry(1).or_insert(0) += v;
+                } else if let Some((a, b)) = split_num(n) {
+                    *m.entry(a).or_insert(0) += v;
+                    *m.entry(b).or_insert(0) += v;
+                } else {
+                    *m.entry(n * 2024).or_insert(0) += v;
+                }
+                m
+            },
+        );
+    }
+
+    stones.values().sum()
+}
+
+/// How many stones are engraved with each number.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub stones: FxHashMap<u64, usize>,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let stones = raw_inp
+            .trim()
+            .split(" ")
+            .try_fold(FxHashMap::default(), |mut m, n| {
+                *m.entry(parse_num(raw_inp, n)?).or_insert(0) += 1;
+                Ok(m)
+            })?;
+
+        Ok(Input { stones })
+    }
+
+    fn part1(Input { stones }: &Self::Parsed<'_>) -> usize {
+        blink::<25>(stones)
+    }
+
+    fn part2(Input { stones }: &Self::Parsed<'_>) -> usize {
+        blink::<75>(stones)
+    }
+}
+
+solution_fns!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+
+    const EXAMPLE_DATA: &str = "125 17";
+
+    #[test]
+    fn test_split_num() {
+        assert_eq!(split_num(12), Some((1, 2)));
+        assert_eq!(split_num(123), None);
+        assert_eq!(split_num(1234), Some((12, 34)));
+        assert_eq!(split_num(12345), None);
+        assert_eq!(split_num(123456), Some((123, 456)));
+
+        assert_eq!(split_num(1), None);
+        assert_eq!(split_num(10), Some((1, 0)));
+        assert_eq!(split_num(100), None);
+        assert_eq!(split_num(1000), Some((10, 0)));
+        assert_eq!(split_num(10000), None);
+        assert_eq!(split_num(100000), Some((100, 0)));
+    }
+
+    #[test]
+    fn test_example() {
+        let Input { stones } = parse(EXAMPLE_DATA).unwrap();
+        assert_eq!(blink::<1>(&stones), 3);
+        assert_eq!(blink::<2>(&stones), 4);
+        assert_eq!(blink::<3>(&stones), 5);
+        assert_eq!(blink::<4>(&stones), 9);
+        assert_eq!(blink::<5>(&stones), 13);
+        assert_eq!(blink::<6>(&stones), 22);
+        assert_eq!(blink::<25>(&stones), 55312);
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day11>();
+    }
+}
@@ -0,0 +1,209 @@
+use crate::bitvec_set::BitVecSet2D;
+use crate::grid_util::{make_byte_grid, Grid};
+use crate::{ParseError, Solution};
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
+fn perimeter_contribution(grid: &Grid<u8>, pos: (usize, usize)) -> (usize, usize) {
+    let mut p1 = 0;
+    let mut p2 = 0;
+
+    let this_tile = grid.get(pos);
+    let same = |offset| grid.get_offset(pos, offset) == this_tile;
+
+    let l = same((0, -1));
+    let r = same((0, 1));
+    let u = same((-1, 0));
+    let d = same((1, 0));
+
+    if !u {
+        p1 += 1;
+        if !l || same((-1, -1)) {
+            p2 += 1;
+        }
+    }
+    if !l {
+        p1 += 1;
+        if !d || same((1, -1)) {
+            p2 += 1;
+        }
+    }
+    if !d {
+        p1 += 1;
+        if !r || same((1, 1)) {
+            p2 += 1;
+        }
+    }
+    if !r {
+        p1 += 1;
+        if !u || same((-1, 1)) {
+            p2 += 1;
+        }
+    }
+
+    (p1, p2)
+}
+
+fn get_region_score(
+    grid: &Grid<u8>,
+    idx: (usize, usize),
+    ever_visited: &mut BitVecSet2D,
+) -> (usize, usize) {
+    let mut q = VecDeque::with_capacity(64);
+    q.push_back(idx);
+
+    let mut area = 0;
+    let mut p1_perimeter = 0;
+    let mut p2_perimeter = 0;
+
+    while let Some(pos) = q.pop_front() {
+        if !ever_visited.insert(pos) {
+            continue;
+        }
+        let this_tile = grid[pos];
+
+        let perimeter_contribs = perimeter_contribution(grid, pos);
+        p1_perimeter += perimeter_contribs.0;
+        p2_perimeter += perimeter_contribs.1;
+        area += 1;
+
+        for next_pos in grid.neighbours4(pos) {
+            if grid[next_pos] == this_tile {
+                q.push_back(next_pos);
+            }
+        }
+    }
+
+    (p1_perimeter * area, p2_perimeter * area)
+}
+
+fn region_scores(grid: &Grid<u8>) -> (usize, usize) {
+    let mut ever_visited = BitVecSet2D::new(grid.dim());
+
+    grid.indices().fold((0, 0), |(p1, p2), idx| {
+        if ever_visited.contains(&idx) {
+            (p1, p2)
+        } else {
+            let (a, b) = get_region_score(grid, idx, &mut ever_visited);
+            (p1 + a, p2 + b)
+        }
+    })
+}
+
+/// The garden plots, each marked with its plant type.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Grid<u8>,
+    prices: OnceLock<(usize, usize)>,
+}
+
+impl Input {
+    /// The total price of fencing every region, by perimeter and by number of
+    /// sides, worked out once by whichever part needs them first.
+    pub fn prices(&self) -> (usize, usize) {
+        *self.prices.get_or_init(|| region_scores(&self.grid))
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Input {
+            grid: make_byte_grid(raw_inp)?.into(),
+            prices: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> usize {
+        input.prices().0
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> usize {
+        input.prices().1
+    }
+}
+
+solution_fns!(Day12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const EXAMPLE_SMALL_1: &str = "AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE_SMALL_2: &str = "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    const EXAMPLE_P2_SMALL_3: &str = "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+    const EXAMPLE_P2_SMALL_4: &str = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day12>(EXAMPLE_DATA).unwrap(), (1930, 1206));
+    }
+
+    #[test]
+    fn test_example_small_1() {
+        assert_eq!(solve::<Day12>(EXAMPLE_SMALL_1).unwrap(), (140, 80));
+    }
+
+    #[test]
+    fn test_example_small_2() {
+        assert_eq!(solve::<Day12>(EXAMPLE_SMALL_2).unwrap(), (772, 436));
+    }
+
+    #[test]
+    fn test_example_p2_small_3() {
+        assert_eq!(solve::<Day12>(EXAMPLE_P2_SMALL_3).unwrap().1, 236);
+    }
+
+    #[test]
+    fn test_example_p2_small_4() {
+        assert_eq!(solve::<Day12>(EXAMPLE_P2_SMALL_4).unwrap().1, 368);
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day12>();
+    }
+}
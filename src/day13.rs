@@ -0,0 +1,190 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use itertools::Itertools;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl ClawMachine {
+    fn best_cost<const OFFSET: i64>(&self) -> Option<i64> {
+        let (tx, ty) = (self.prize.0 + OFFSET, self.prize.1 + OFFSET);
+        let (ax, ay) = self.a;
+        let (bx, by) = self.b;
+
+        // Simultaneous equations
+        // NA * ax + NB * bx = tx
+        // NA * ay + NB * by = ty
+        let nb = (tx * ay - ax * ty) / (bx * ay - ax * by);
+        let na = (ty - nb * by) / ay;
+
+        let actual = (na * ax + nb * bx, na * ay + nb * by);
+
+        (na >= 0 && nb >= 0 && actual == (tx, ty)).then_some(na * 3 + nb)
+    }
+}
+
+/// Parses e.g. `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+fn parse_xy(s: &str, line: &str) -> Result<(i64, i64), ParseError> {
+    let (_, xy) = split_once(s, line, ": ")?;
+    let (x, y) = split_once(s, xy, ", ")?;
+
+    let coord = |c: &str| {
+        let n = c
+            .get(2..)
+            .ok_or_else(|| ParseError::new(s, c, "expected a coordinate"))?;
+        parse_num(s, n)
+    };
+
+    Ok((coord(x)?, coord(y)?))
+}
+
+impl FromStr for ClawMachine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b, prize) = s
+            .lines()
+            .map(|line| parse_xy(s, line))
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, s, "expected 2 buttons and a prize"))?;
+
+        Ok(ClawMachine {
+            a: a?,
+            b: b?,
+            prize: prize?,
+        })
+    }
+}
+
+const P2_OFFSET: i64 = 10000000000000;
+
+/// The claw machines.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub machines: Vec<ClawMachine>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Input;
+    type P1 = i64;
+    type P2 = i64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let machines = raw_inp
+            .split("\n\n")
+            .filter(|group| !group.trim().is_empty())
+            .map(|group| {
+                group
+                    .parse()
+                    .map_err(|e: ParseError| e.within(raw_inp, group))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { machines })
+    }
+
+    fn part1(Input { machines }: &Self::Parsed<'_>) -> i64 {
+        machines
+            .iter()
+            .map(|m| m.best_cost::<0>().unwrap_or(0))
+            .sum()
+    }
+
+    fn part2(Input { machines }: &Self::Parsed<'_>) -> i64 {
+        machines
+            .iter()
+            .map(|m| m.best_cost::<P2_OFFSET>().unwrap_or(0))
+            .sum()
+    }
+}
+
+solution_fns!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    const EX1: ClawMachine = ClawMachine {
+        a: (94, 34),
+        b: (22, 67),
+        prize: (8400, 5400),
+    };
+
+    const EX2: ClawMachine = ClawMachine {
+        a: (26, 66),
+        b: (67, 21),
+        prize: (12748, 12176),
+    };
+
+    const EX3: ClawMachine = ClawMachine {
+        a: (17, 86),
+        b: (84, 37),
+        prize: (7870, 6450),
+    };
+
+    const EX4: ClawMachine = ClawMachine {
+        a: (69, 23),
+        b: (27, 71),
+        prize: (18641, 10279),
+    };
+
+    #[test]
+    fn test_example_p1() {
+        assert_eq!(solve::<Day13>(EXAMPLE_DATA).unwrap().0, 480);
+
+        assert_eq!(EX1.best_cost::<0>(), Some(280));
+        assert_eq!(EX2.best_cost::<0>(), None);
+        assert_eq!(EX3.best_cost::<0>(), Some(200));
+        assert_eq!(EX4.best_cost::<0>(), None);
+    }
+
+    #[test]
+    fn test_example_p2() {
+        assert!(EX1.best_cost::<P2_OFFSET>().is_none());
+        assert!(EX2.best_cost::<P2_OFFSET>().is_some());
+        assert!(EX3.best_cost::<P2_OFFSET>().is_none());
+        assert!(EX4.best_cost::<P2_OFFSET>().is_some());
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA);
+        assert_eq!(
+            solve::<Day13>(&raw_inp).unwrap(),
+            solve::<Day13>(EXAMPLE_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day13>();
+    }
+}
@@ -0,0 +1,198 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use bitvec::prelude::*;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    px: i32,
+    py: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn position_after<const DIM_X: i32, const DIM_Y: i32>(&self, n: i32) -> (i32, i32) {
+        (
+            (self.px + n * self.vx).rem_euclid(DIM_X),
+            (self.py + n * self.vy).rem_euclid(DIM_Y),
+        )
+    }
+
+    fn forward_n_inplace<const DIM_X: i32, const DIM_Y: i32>(&mut self, n: i32) {
+        let new_pos = self.position_after::<DIM_X, DIM_Y>(n);
+        self.px = new_pos.0;
+        self.py = new_pos.1;
+    }
+
+    fn reverse_one_inplace<const DIM_X: i32, const DIM_Y: i32>(&mut self) {
+        // Try to encourage compiler to generate branchless code, and avoid
+        // slow rem_euclid
+        self.px -= self.vx;
+        if self.px < 0 {
+            self.px += DIM_X;
+        }
+        if self.px >= DIM_X {
+            self.px -= DIM_X;
+        }
+        self.py -= self.vy;
+        if self.py < 0 {
+            self.py += DIM_Y;
+        }
+        if self.py >= DIM_Y {
+            self.py -= DIM_Y;
+        }
+    }
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (p, v) = split_once(s, s, " ")?;
+        let (_, p) = split_once(s, p, "=")?;
+        let (_, v) = split_once(s, v, "=")?;
+
+        let (px, py) = split_once(s, p, ",")?;
+        let (vx, vy) = split_once(s, v, ",")?;
+
+        Ok(Robot {
+            px: parse_num(s, px)?,
+            py: parse_num(s, py)?,
+            vx: parse_num(s, vx)?,
+            vy: parse_num(s, vy)?,
+        })
+    }
+}
+
+fn calculate_p1<const DIM_X: i32, const DIM_Y: i32>(robots: &[Robot]) -> i32 {
+    let (mut ul, mut ur, mut dl, mut dr) = (0, 0, 0, 0);
+
+    robots
+        .iter()
+        .map(|r| r.position_after::<DIM_X, DIM_Y>(100))
+        .for_each(|(x, y)| {
+            let left = x < DIM_X / 2;
+            let right = x > DIM_X / 2;
+            let up = y < DIM_Y / 2;
+            let down = y > DIM_Y / 2;
+
+            match (left, up, right, down) {
+                (true, true, false, false) => ul += 1,
+                (true, false, false, true) => dl += 1,
+                (false, true, true, false) => ur += 1,
+                (false, false, true, true) => dr += 1,
+                _ => {}
+            }
+        });
+
+    ul * ur * dl * dr
+}
+
+fn calculate_p2<const DIM_X: i32, const DIM_Y: i32>(robots: &mut [Robot]) -> i32 {
+    robots
+        .iter_mut()
+        .for_each(|r| r.forward_n_inplace::<DIM_X, DIM_Y>(DIM_X * DIM_Y));
+
+    let mut n = DIM_X * DIM_Y;
+    let mut bv = bitvec![u8, Lsb0; 0; (DIM_X * DIM_Y) as usize];
+
+    while n > 0 {
+        robots.iter_mut().for_each(|r| {
+            r.reverse_one_inplace::<DIM_X, DIM_Y>();
+            bv.set((r.px * DIM_Y + r.py) as usize, true);
+        });
+
+        // u wot m8
+        if bv.as_raw_slice().contains(&0xFF) {
+            return n - 1;
+        }
+
+        n -= 1;
+        bv.fill(false);
+    }
+
+    panic!("no solution");
+}
+
+fn parse_robots(raw_inp: &str) -> Result<Vec<Robot>, ParseError> {
+    raw_inp
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|e: ParseError| e.within(raw_inp, line))
+        })
+        .collect()
+}
+
+/// The robots' starting positions and velocities.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub robots: Vec<Robot>,
+}
+
+pub struct Day14<const DIM_X: i32 = 101, const DIM_Y: i32 = 103>;
+
+impl<const DIM_X: i32, const DIM_Y: i32> Solution for Day14<DIM_X, DIM_Y> {
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = Input;
+    type P1 = i32;
+    type P2 = i32;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(Input {
+            robots: parse_robots(raw_inp)?,
+        })
+    }
+
+    fn part1(Input { robots }: &Self::Parsed<'_>) -> i32 {
+        calculate_p1::<DIM_X, DIM_Y>(robots)
+    }
+
+    fn part2(Input { robots }: &Self::Parsed<'_>) -> i32 {
+        calculate_p2::<DIM_X, DIM_Y>(&mut robots.clone())
+    }
+}
+
+solution_fns!(Day14);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            calculate_p1::<11, 7>(&parse_robots(EXAMPLE_DATA).unwrap()),
+            12
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = solve::<Day14>("p=0,4 v=3,-3\np=6,3 v=-1,q\n").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (Some(14), 2, 12));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day14>();
+    }
+}
@@ -0,0 +1,265 @@
+use crate::direction::Direction;
+use crate::parse_util::split_once;
+use crate::{grid_util::make_byte_grid, ParseError, Solution};
+use ndarray::Array2;
+use std::collections::VecDeque;
+
+fn score_grid<const MATCH: u8>(grid: &Array2<u8>) -> usize {
+    grid.indexed_iter()
+        .filter_map(|(pos, &v)| (v == MATCH).then_some(100 * pos.0 + pos.1))
+        .sum()
+}
+
+fn calculate_p1(mut grid: Array2<u8>, moves: &[Direction], start_pos: (usize, usize)) -> usize {
+    let mut pos = start_pos;
+    for mv in moves {
+        let mut next_pos = mv.step(pos);
+        let next_robot_pos = next_pos;
+
+        while let Some(b'O') = grid.get(next_pos) {
+            next_pos = mv.step(next_pos);
+        }
+
+        if grid.get(next_pos) == Some(&b'.') {
+            grid[pos] = b'.';
+            grid[next_pos] = b'O';
+            grid[next_robot_pos] = b'@';
+            pos = next_robot_pos;
+        }
+    }
+
+    score_grid::<b'O'>(&grid)
+}
+
+fn blow_up_grid(grid: &Array2<u8>) -> Array2<u8> {
+    Array2::from_shape_fn((grid.dim().0, grid.dim().1 * 2), |idx| {
+        let even = idx.1 % 2 == 0;
+        match (grid[(idx.0, idx.1 / 2)], even) {
+            (b'#', _) => b'#',
+            (b'.', _) => b'.',
+            (b'O', true) => b'[',
+            (b'O', false) => b']',
+            (b'@', true) => b'@',
+            (b'@', false) => b'.',
+            _ => panic!("invalid type"),
+        }
+    })
+}
+
+fn calculate_p2(
+    original_grid: &Array2<u8>,
+    moves: &[Direction],
+    start_pos: (usize, usize),
+) -> usize {
+    let mut grid = blow_up_grid(original_grid);
+    let mut pos = (start_pos.0, start_pos.1 * 2);
+
+    let mut q = VecDeque::default();
+    let mut moved_from = Vec::default();
+
+    for mv in moves {
+        let next_pos = mv.step(pos);
+
+        if let Some(b'.') = grid.get(next_pos) {
+            pos = next_pos;
+            continue;
+        }
+
+        q.clear();
+        moved_from.clear();
+
+        q.push_back(pos);
+
+        let mut can_move = true;
+
+        while let Some(p) = q.pop_front() {
+            if moved_from.contains(&p) {
+                continue;
+            }
+
+            let np = mv.step(p);
+            moved_from.push(p);
+
+            match grid.get(np) {
+                Some(&b'[') => {
+                    q.push_back(np);
+                    if mv.is_vertical() {
+                        q.push_back((np.0, np.1 + 1));
+                    }
+                }
+                Some(&b']') => {
+                    q.push_back(np);
+                    if mv.is_vertical() {
+                        q.push_back((np.0, np.1.wrapping_add_signed(-1)));
+                    }
+                }
+                Some(b'#') | None => {
+                    can_move = false;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        if can_move {
+            // This works because we built up moved_from in order of increasing
+            // distance, so can iterate back in reverse
+            moved_from.iter().rev().for_each(|&pos| {
+                let np = mv.step(pos);
+                grid[np] = grid[pos];
+                grid[pos] = b'.';
+            });
+
+            pos = next_pos;
+        }
+    }
+
+    score_grid::<b'['>(&grid)
+}
+
+/// The warehouse, the robot's moves, and where the robot starts.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Array2<u8>,
+    pub moves: Vec<Direction>,
+    pub robot: (usize, usize),
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (head, tail) = split_once(raw_inp, raw_inp, "\n\n")?;
+
+        let grid = make_byte_grid(head)?;
+
+        if let Some(((r, c), &v)) = grid.indexed_iter().find(|(_, v)| !b"#.O@".contains(v)) {
+            return Err(ParseError::at(
+                r + 1,
+                c + 1,
+                &(v as char).to_string(),
+                "unknown tile",
+            ));
+        }
+
+        let robot = grid
+            .indexed_iter()
+            .find(|(_, &v)| v == b'@')
+            .map(|(pos, _)| pos)
+            .ok_or_else(|| ParseError::missing(head, "no robot start position"))?;
+
+        let moves = tail
+            .char_indices()
+            .filter(|&(_, c)| c != '\n')
+            .map(
+                |(i, c)| match u8::try_from(c).ok().and_then(Direction::from_arrow) {
+                    Some(dir) => Ok(dir),
+                    None => Err(ParseError::new(
+                        raw_inp,
+                        &tail[i..i + c.len_utf8()],
+                        "unknown move",
+                    )),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Input { grid, moves, robot })
+    }
+
+    fn part1(Input { grid, moves, robot }: &Self::Parsed<'_>) -> usize {
+        calculate_p1(grid.clone(), moves, *robot)
+    }
+
+    fn part2(Input { grid, moves, robot }: &Self::Parsed<'_>) -> usize {
+        calculate_p2(grid, moves, *robot)
+    }
+}
+
+solution_fns!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    const SMALL_EX: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    #[test]
+    fn test_small_example_p1() {
+        assert_eq!(solve::<Day15>(SMALL_EX).unwrap().0, 2028);
+    }
+
+    #[test]
+    fn test_scoring_p1() {
+        let grid = "#######
+#...O..
+#......
+";
+        assert_eq!(score_grid::<b'O'>(&make_byte_grid(grid).unwrap()), 104);
+    }
+
+    #[test]
+    fn test_scoring_p2() {
+        let grid = "##########
+##...[]...
+##........
+";
+        assert_eq!(score_grid::<b'['>(&make_byte_grid(grid).unwrap()), 105);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day15>(EXAMPLE_DATA).unwrap(), (10092, 9021));
+    }
+
+    #[test]
+    fn test_crlf() {
+        let raw_inp = EXAMPLE_DATA.replace('\n', "\r\n");
+        assert_eq!(solve::<Day15>(&raw_inp).unwrap(), (10092, 9021));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day15>();
+    }
+}
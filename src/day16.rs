@@ -0,0 +1,181 @@
+use crate::bitvec_set::BitVecSet2D;
+use crate::direction::Direction;
+use crate::grid_util::{make_grid_with_markers, Grid};
+use crate::pathfinding::{dijkstra, from_fn, Graph, Paths};
+use crate::{ParseError, Solution};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Floor,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(b: u8) -> Result<Tile, u8> {
+        match b {
+            b'#' => Ok(Tile::Wall),
+            b'.' => Ok(Tile::Floor),
+            _ => Err(b),
+        }
+    }
+}
+
+/// Where the reindeer is and which way it faces.
+pub type State = ((usize, usize), Direction);
+
+fn maze(grid: &Grid<Tile>) -> impl Graph<Node = State> + '_ {
+    from_fn(grid.dim(), |(pos, dir): State| {
+        let step = grid
+            .step(pos, dir.offset())
+            .filter(|&next| grid[next] != Tile::Wall)
+            .map(|next| ((next, dir), 1));
+
+        step.into_iter().chain([
+            ((pos, dir.turn_left()), 1000),
+            ((pos, dir.turn_right()), 1000),
+        ])
+    })
+}
+
+fn search(maze: &impl Graph<Node = State>, start: (usize, usize)) -> Paths<State> {
+    dijkstra(maze, [(start, Direction::Right)], None)
+}
+
+fn best_score(paths: &Paths<State>, end: &(usize, usize)) -> u32 {
+    Direction::ALL
+        .into_iter()
+        .filter_map(|d| paths.distance((*end, d)))
+        .min()
+        .expect("no solution")
+}
+
+fn best_path_tiles(
+    grid: &Grid<Tile>,
+    maze: &impl Graph<Node = State>,
+    paths: &Paths<State>,
+    end: &(usize, usize),
+) -> usize {
+    let p1_score = best_score(paths, end);
+    let best_ends = Direction::ALL
+        .into_iter()
+        .map(|d| (*end, d))
+        .filter(|&state| paths.distance(state) == Some(p1_score));
+
+    let mut tiles = BitVecSet2D::new(grid.dim());
+    tiles.extend(
+        paths
+            .shortest_path_dag(maze)
+            .nodes_on_paths_to(best_ends)
+            .iter()
+            .map(|(pos, _)| pos),
+    );
+    tiles.len()
+}
+
+/// The maze, and where the reindeer starts, facing east, and must end.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Grid<Tile>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    paths: OnceLock<Paths<State>>,
+}
+
+impl Input {
+    /// The cheapest ways through the maze from the start, worked out once by
+    /// whichever part needs them first.
+    pub fn paths(&self) -> &Paths<State> {
+        self.paths
+            .get_or_init(|| search(&maze(&self.grid), self.start))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed<'a> = Input;
+    type P1 = u32;
+    type P2 = usize;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (grid, [start, end]) = make_grid_with_markers(raw_inp, [b'S', b'E'], b'.')?;
+        Ok(Input {
+            grid,
+            start,
+            end,
+            paths: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> u32 {
+        best_score(input.paths(), &input.end)
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> usize {
+        best_path_tiles(&input.grid, &maze(&input.grid), input.paths(), &input.end)
+    }
+}
+
+solution_fns!(Day16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA_1: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+    const EXAMPLE_DATA_2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn test_example_1() {
+        assert_eq!(solve::<Day16>(EXAMPLE_DATA_1).unwrap(), (7036, 45));
+    }
+
+    #[test]
+    fn test_example_2() {
+        assert_eq!(solve::<Day16>(EXAMPLE_DATA_2).unwrap(), (11048, 64));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day16>();
+    }
+}
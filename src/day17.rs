@@ -0,0 +1,222 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use itertools::Itertools;
+
+fn run_program(mut a: u64, mut b: u64, mut c: u64, program: &[u64]) -> Vec<u64> {
+    let mut ip = 0;
+    let mut out = Vec::with_capacity(16);
+
+    while ip + 1 < program.len() {
+        let inst = program[ip];
+        let literal = program[ip + 1];
+        let combo = match literal {
+            0..=3 => literal,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => u64::MAX,
+        };
+
+        match inst {
+            0 => {
+                debug_assert!(combo != u64::MAX);
+                a /= 2_u64.pow(combo as u32);
+            }
+            1 => {
+                b ^= literal;
+            }
+            2 => {
+                debug_assert!(combo != u64::MAX);
+                b = combo.rem_euclid(8);
+            }
+            3 => {
+                if a != 0 {
+                    ip = literal as usize;
+                    continue;
+                }
+            }
+            4 => {
+                b ^= c;
+            }
+            5 => {
+                debug_assert!(combo != u64::MAX);
+                out.push(combo.rem_euclid(8));
+            }
+            6 => {
+                debug_assert!(combo != u64::MAX);
+                b = a / 2_u64.pow(combo as u32);
+            }
+            7 => {
+                debug_assert!(combo != u64::MAX);
+                c = a / 2_u64.pow(combo as u32);
+            }
+            _ => panic!("invalid instruction"),
+        }
+
+        ip += 2;
+    }
+    out
+}
+
+fn get_a(coeffs: &[u64]) -> u64 {
+    coeffs
+        .iter()
+        .enumerate()
+        .map(|(p, n)| 8u64.pow((coeffs.len() - p - 1) as u32) * n)
+        .sum()
+}
+
+fn find_a(nums: &mut Vec<u64>, program: &[u64], b: u64, c: u64) -> Option<u64> {
+    if nums.len() == program.len() {
+        return Some(get_a(nums));
+    }
+
+    (0..8)
+        .filter_map(|i| {
+            nums.push(i);
+
+            let length = nums.len();
+            let solution = run_program(get_a(nums), b, c, program)
+                .ends_with(&program[program.len() - length..])
+                .then(|| find_a(nums, program, b, c))
+                .flatten();
+
+            nums.pop();
+            solution
+        })
+        .min()
+}
+
+/// The initial values of registers A, B and C, and the program.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub registers: (u64, u64, u64),
+    pub program: Vec<u64>,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed<'a> = Input;
+    type P1 = String;
+    type P2 = u64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (head, tail) = split_once(raw_inp, raw_inp, "\n\n")?;
+
+        let registers = head
+            .lines()
+            .map(|line| {
+                let (_, value) = split_once(raw_inp, line, ": ")?;
+                parse_num(raw_inp, value)
+            })
+            .collect::<Result<Vec<u64>, _>>()?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(raw_inp, head, "expected 3 registers"))?;
+
+        let values = tail
+            .strip_prefix("Program: ")
+            .ok_or_else(|| ParseError::new(raw_inp, tail, "expected \"Program: \""))?
+            .split(",")
+            .map(|x| match parse_num(raw_inp, x.trim())? {
+                n @ 0..=7 => Ok((x, n)),
+                _ => Err(ParseError::new(raw_inp, x, "expected a 3-bit number")),
+            })
+            .collect::<Result<Vec<(&str, u64)>, _>>()?;
+
+        // Only even addresses are ever run, as jumps must be to them, so
+        // checking each instruction there rules out every invalid operand.
+        for pair in values.chunks(2) {
+            match pair {
+                [(_, 0 | 2 | 5 | 6 | 7), (x, 7)] => {
+                    return Err(ParseError::new(raw_inp, x, "combo operand 7 is reserved"));
+                }
+                [(_, 3), (x, target)] if target % 2 == 1 => {
+                    return Err(ParseError::new(raw_inp, x, "jump to an odd address"));
+                }
+                _ => {}
+            }
+        }
+        let program = values.into_iter().map(|(_, n)| n).collect();
+
+        Ok(Input { registers, program })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> String {
+        let (a, b, c) = input.registers;
+        run_program(a, b, c, &input.program).into_iter().join(",")
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> u64 {
+        let (_, b, c) = input.registers;
+        find_a(&mut Vec::with_capacity(16), &input.program, b, c).expect("no p2 solution?")
+    }
+}
+
+solution_fns!(Day17);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn test_simple_example_1() {
+        assert_eq!(run_program(10, 0, 0, &[5, 0, 5, 1, 5, 4]), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_simple_example_2() {
+        assert_eq!(
+            run_program(2024, 0, 0, &[0, 1, 5, 4, 3, 0]),
+            &[4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_example_p1() {
+        assert_eq!(
+            run_program(729, 0, 0, &[0, 1, 5, 4, 3, 0]),
+            &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_invalid_operands() {
+        let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+        let parse = |program| Day17::parse(&format!("{}Program: {}\n", registers, program));
+
+        let err = parse("1,7,0,7,3,0").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.reason.as_str()),
+            (5, 16, "combo operand 7 is reserved")
+        );
+        let err = parse("5,4,3,1").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.reason.as_str()),
+            (5, 16, "jump to an odd address")
+        );
+        assert!(parse("1,7,5,4,3,0").is_ok());
+    }
+
+    #[test]
+    fn test_example_p2() {
+        assert_eq!(solve::<Day17>(EXAMPLE_DATA).unwrap().1, 117440)
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day17>();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::grid_util::Grid;
+use crate::parse_util::{parse_num, split_once};
+use crate::pathfinding::{bfs, from_fn};
+use crate::{ParseError, Solution};
+use ndarray::Array2;
+
+fn pathfind<const DIM: usize>(grid: &Grid<usize>, n: usize) -> Option<u32> {
+    let graph = from_fn(grid.dim(), |pos| {
+        // The exit can always be reached, even once a byte has fallen on it.
+        grid.neighbours4(pos)
+            .filter(move |&next| grid[next] >= n || next == (DIM, DIM))
+            .map(|next| (next, 1))
+    });
+    bfs(&graph, [(0, 0)], Some((DIM, DIM))).distance((DIM, DIM))
+}
+
+/// The memory space and the bytes falling into it.
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// When each position is corrupted, as the index of the byte that falls
+    /// there, or `usize::MAX` if none does.
+    pub grid: Grid<usize>,
+    /// Each byte's `(x, y)` position, in the order they fall.
+    pub bytes: Vec<(usize, usize)>,
+}
+
+pub struct Day18<const DIM: usize = 70, const P1_ITER: usize = 1024>;
+
+impl<const DIM: usize, const P1_ITER: usize> Solution for Day18<DIM, P1_ITER> {
+    const DAY: u8 = 18;
+
+    type Parsed<'a> = Input;
+    type P1 = u32;
+    type P2 = String;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut grid = Array2::from_elem((DIM + 1, DIM + 1), usize::MAX);
+
+        let bytes = raw_inp
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (a, b) = split_once(raw_inp, line, ",")?;
+                let a = parse_num(raw_inp, a)?;
+                let b = parse_num(raw_inp, b)?;
+                if a > DIM || b > DIM {
+                    return Err(ParseError::new(raw_inp, line, "byte is outside the grid"));
+                }
+                Ok((a, b))
+            })
+            .collect::<Result<Vec<(usize, usize)>, _>>()?;
+
+        bytes.iter().enumerate().for_each(|(n, &(x, y))| {
+            grid[(y, x)] = n;
+        });
+
+        Ok(Input {
+            grid: grid.into(),
+            bytes,
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> u32 {
+        pathfind::<DIM>(&input.grid, P1_ITER).expect("no p1 solution?")
+    }
+
+    fn part2(Input { grid, bytes }: &Self::Parsed<'_>) -> String {
+        let mut p2_upper = bytes.len();
+        let mut p2_lower = P1_ITER;
+
+        while p2_lower + 1 != p2_upper {
+            let n = (p2_lower + p2_upper) / 2;
+            if pathfind::<DIM>(grid, n).is_some() {
+                p2_lower = n;
+            } else {
+                p2_upper = n;
+            }
+        }
+
+        let (x, y) = bytes[p2_lower];
+        format!("{},{}", x, y)
+    }
+}
+
+solution_fns!(Day18);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::{solve, solve_parts, Parts};
+
+    const EXAMPLE_DATA: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            solve::<Day18<6, 12>>(EXAMPLE_DATA).unwrap(),
+            (22, "6,1".to_string())
+        )
+    }
+
+    #[test]
+    fn test_corrupted_exit() {
+        assert_eq!(
+            solve_parts::<Day18<6, 1>>("6,6\n0,1\n", Parts::One).unwrap(),
+            (Some(12), None)
+        );
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA);
+        assert_eq!(
+            solve::<Day18<6, 12>>(&raw_inp).unwrap(),
+            solve::<Day18<6, 12>>(EXAMPLE_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day18>();
+    }
+}
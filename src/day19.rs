@@ -0,0 +1,107 @@
+use crate::parse_util::split_once;
+use crate::{ParseError, Solution};
+use ahash::AHashMap;
+use rayon::prelude::*;
+use std::sync::OnceLock;
+
+fn valid_paths<'a>(line: &'a str, towels: &[&str], cache: &mut AHashMap<&'a str, u64>) -> u64 {
+    if line.is_empty() {
+        1
+    } else if let Some(&cached_result) = cache.get(line) {
+        cached_result
+    } else {
+        let r = towels
+            .iter()
+            .filter(|&&t| line.starts_with(t))
+            .map(|&t| valid_paths(&line[t.len()..], towels, cache))
+            .sum();
+
+        cache.insert(line, r);
+        r
+    }
+}
+
+/// The available towel patterns and the designs to make from them.
+#[derive(Debug, Clone)]
+pub struct Input<'a> {
+    pub towels: Vec<&'a str>,
+    pub designs: Vec<&'a str>,
+    counts: OnceLock<Vec<u64>>,
+}
+
+impl Input<'_> {
+    /// The number of ways to make each design, worked out once by whichever
+    /// part needs them first.
+    pub fn arrangement_counts(&self) -> &[u64] {
+        self.counts.get_or_init(|| {
+            self.designs
+                .par_iter()
+                .map(|line| valid_paths(line, &self.towels, &mut AHashMap::default()))
+                .collect()
+        })
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed<'a> = Input<'a>;
+    type P1 = u64;
+    type P2 = u64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (towels, arrangements) = split_once(raw_inp, raw_inp, "\n\n")?;
+        let towels = towels.trim().split(", ").collect::<Vec<_>>();
+
+        Ok(Input {
+            towels,
+            designs: arrangements.lines().collect(),
+            counts: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> u64 {
+        input
+            .arrangement_counts()
+            .iter()
+            .map(|&n| (n >= 1) as u64)
+            .sum()
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> u64 {
+        input.arrangement_counts().iter().sum()
+    }
+}
+
+solution_fns!(Day19);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day19>(EXAMPLE_DATA).unwrap(), (6, 16));
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day19>();
+    }
+}
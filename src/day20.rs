@@ -0,0 +1,201 @@
+use crate::grid_util::{make_grid_with_markers, Grid};
+use crate::pathfinding::{bfs, from_fn};
+use crate::{ParseError, Solution};
+use ndarray::Array2;
+use rayon::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Track,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(b: u8) -> Result<Tile, u8> {
+        match b {
+            b'#' => Ok(Tile::Wall),
+            b'.' => Ok(Tile::Track),
+            _ => Err(b),
+        }
+    }
+}
+
+/// The way along the track, and how far along it each position is.
+type Track = (Vec<(usize, usize)>, Array2<u32>);
+
+// Assumes the path never branches, so covers the whole track. `None` if the
+// end can't be reached.
+fn get_path_and_costs(
+    grid: &Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Option<Track> {
+    let graph = from_fn(grid.dim(), |pos| {
+        grid.neighbours4(pos)
+            .filter(|&next| grid[next] == Tile::Track)
+            .map(|next| (next, 1))
+    });
+    let paths = bfs(&graph, [start], None);
+
+    let path = paths.path_to(end)?;
+    let costs = Array2::from_shape_vec(grid.dim(), paths.into_distances())
+        .expect("a distance for every position");
+    Some((path, costs))
+}
+
+fn get_cheats<const N: usize, const SAVINGS: u32>(pos: (usize, usize), costs: &Array2<u32>) -> u32 {
+    let (yp, xp) = pos;
+    let ys = yp.saturating_sub(N);
+    let ye = (costs.dim().0 - 1).min(yp + N);
+
+    let cp = costs[(yp, xp)];
+    debug_assert!(cp != u32::MAX);
+
+    let mut ans = 0;
+    for y in ys..ye + 1 {
+        let yd = y.abs_diff(yp);
+        let xs = xp.saturating_sub(N - yd);
+        let xe = (costs.dim().1 - 1).min(xp + N - yd);
+
+        for x in xs..xe + 1 {
+            let cc = costs[(y, x)];
+            let diff = (x.abs_diff(xp) + yd) as u32;
+            ans += (cc != u32::MAX && cc >= cp + diff + SAVINGS) as u32;
+        }
+    }
+
+    ans
+}
+
+fn count_cheats<const N: usize, const SAVINGS: u32>(
+    path: &[(usize, usize)],
+    costs: &Array2<u32>,
+) -> u32 {
+    let bound = path.len().saturating_sub(SAVINGS as usize);
+    path[..bound]
+        .into_par_iter()
+        .map(|start| get_cheats::<N, SAVINGS>(*start, costs))
+        .sum()
+}
+
+/// The racetrack, where it starts and ends, and the way along it with how far
+/// along it each position is, which both parts cheat from.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub grid: Grid<Tile>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    pub path: Vec<(usize, usize)>,
+    pub costs: Array2<u32>,
+}
+
+pub struct Day20<const SAVINGS: u32 = 100>;
+
+impl<const SAVINGS: u32> Solution for Day20<SAVINGS> {
+    const DAY: u8 = 20;
+
+    type Parsed<'a> = Input;
+    type P1 = u32;
+    type P2 = u32;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (grid, [start, end]) = make_grid_with_markers(raw_inp, [b'S', b'E'], b'.')?;
+        // Following the track checks the end can be reached at all, so is
+        // part of parsing. Counting cheats is what the parts time.
+        let (path, costs) = get_path_and_costs(&grid, start, end)
+            .ok_or_else(|| ParseError::at(end.0 + 1, end.1 + 1, "E", "no path from S to E"))?;
+        Ok(Input {
+            grid,
+            start,
+            end,
+            path,
+            costs,
+        })
+    }
+
+    fn part1(Input { path, costs, .. }: &Self::Parsed<'_>) -> u32 {
+        count_cheats::<2, SAVINGS>(path, costs)
+    }
+
+    fn part2(Input { path, costs, .. }: &Self::Parsed<'_>) -> u32 {
+        count_cheats::<20, SAVINGS>(path, costs)
+    }
+}
+
+solution_fns!(Day20);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn test_example_p1() {
+        assert_eq!(solve::<Day20<2>>(EXAMPLE_DATA).unwrap().0, 44);
+        assert_eq!(solve::<Day20<4>>(EXAMPLE_DATA).unwrap().0, 30);
+        assert_eq!(solve::<Day20<6>>(EXAMPLE_DATA).unwrap().0, 16);
+        assert_eq!(solve::<Day20<8>>(EXAMPLE_DATA).unwrap().0, 14);
+        assert_eq!(solve::<Day20<10>>(EXAMPLE_DATA).unwrap().0, 10);
+        assert_eq!(solve::<Day20<12>>(EXAMPLE_DATA).unwrap().0, 8);
+        assert_eq!(solve::<Day20<20>>(EXAMPLE_DATA).unwrap().0, 5);
+        assert_eq!(solve::<Day20<36>>(EXAMPLE_DATA).unwrap().0, 4);
+        assert_eq!(solve::<Day20<38>>(EXAMPLE_DATA).unwrap().0, 3);
+        assert_eq!(solve::<Day20<40>>(EXAMPLE_DATA).unwrap().0, 2);
+        assert_eq!(solve::<Day20<64>>(EXAMPLE_DATA).unwrap().0, 1);
+    }
+
+    #[test]
+    fn test_example_p2() {
+        assert_eq!(solve::<Day20<50>>(EXAMPLE_DATA).unwrap().1, 285);
+        assert_eq!(solve::<Day20<52>>(EXAMPLE_DATA).unwrap().1, 253);
+        assert_eq!(solve::<Day20<54>>(EXAMPLE_DATA).unwrap().1, 222);
+        assert_eq!(solve::<Day20<56>>(EXAMPLE_DATA).unwrap().1, 193);
+        assert_eq!(solve::<Day20<58>>(EXAMPLE_DATA).unwrap().1, 154);
+        assert_eq!(solve::<Day20<60>>(EXAMPLE_DATA).unwrap().1, 129);
+        assert_eq!(solve::<Day20<62>>(EXAMPLE_DATA).unwrap().1, 106);
+        assert_eq!(solve::<Day20<64>>(EXAMPLE_DATA).unwrap().1, 86);
+        assert_eq!(solve::<Day20<66>>(EXAMPLE_DATA).unwrap().1, 67);
+        assert_eq!(solve::<Day20<68>>(EXAMPLE_DATA).unwrap().1, 55);
+        assert_eq!(solve::<Day20<70>>(EXAMPLE_DATA).unwrap().1, 41);
+        assert_eq!(solve::<Day20<72>>(EXAMPLE_DATA).unwrap().1, 29);
+        assert_eq!(solve::<Day20<74>>(EXAMPLE_DATA).unwrap().1, 7);
+        assert_eq!(solve::<Day20<76>>(EXAMPLE_DATA).unwrap().1, 3);
+    }
+
+    #[test]
+    fn test_track_shorter_than_savings() {
+        assert_eq!(solve::<Day20>(EXAMPLE_DATA).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let err = solve::<Day20>("#####\n#S#E#\n#####\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.reason, "no path from S to E");
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day20>();
+    }
+}
@@ -0,0 +1,269 @@
+use crate::direction::Direction;
+use crate::{ParseError, Solution};
+use ahash::AHashMap;
+use ahash::AHashSet;
+use itertools::Itertools;
+use ndarray::Array2;
+
+type Cache = AHashMap<(usize, Vec<u8>), u64>;
+
+struct RequiredMoveCounts {
+    up: usize,
+    down: usize,
+    left: usize,
+    right: usize,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct Robot {
+    y: usize,
+    x: usize,
+    keypad: Array2<u8>,
+}
+
+impl Robot {
+    fn move_bot(&mut self, key: u8) {
+        match Direction::from_arrow(key) {
+            Some(dir) => (self.y, self.x) = dir.step((self.y, self.x)),
+            None => assert_eq!(key, b'A', "invalid move"),
+        }
+    }
+
+    fn required_moves(&self, target: (usize, usize)) -> RequiredMoveCounts {
+        let y = target.0.abs_diff(self.y);
+        let x = target.1.abs_diff(self.x);
+
+        let left = if target.1 < self.x { x } else { 0 };
+        let right = if target.1 > self.x { x } else { 0 };
+        let down = if target.0 > self.y { y } else { 0 };
+        let up = if target.0 < self.y { y } else { 0 };
+
+        RequiredMoveCounts {
+            left,
+            right,
+            up,
+            down,
+        }
+    }
+
+    fn type_code(&mut self, code: &[u8]) -> Vec<Vec<u8>> {
+        let mut ans: Vec<Vec<u8>> = vec![];
+        for c in code {
+            let (target_y, target_x) = self.find(*c);
+            let possible_moves = self.possible_code_sequences((target_y, target_x));
+
+            if !ans.is_empty() {
+                let mut new_answers = AHashSet::default();
+                for a in &ans {
+                    for pos in &possible_moves {
+                        new_answers.insert(
+                            a.iter()
+                                .copied()
+                                .chain(pos.iter().copied())
+                                .collect::<Vec<_>>(),
+                        );
+                    }
+                }
+
+                ans = new_answers.into_iter().collect_vec();
+            } else {
+                ans = possible_moves;
+            }
+
+            self.y = target_y;
+            self.x = target_x;
+        }
+        ans
+    }
+
+    fn possible_code_sequences(&self, target: (usize, usize)) -> Vec<Vec<u8>> {
+        let mut ans = vec![];
+        let required_moves = self.required_moves(target);
+
+        ans.extend(vec![b'^'; required_moves.up]);
+        ans.extend(vec![b'v'; required_moves.down]);
+        ans.extend(vec![b'<'; required_moves.left]);
+        ans.extend(vec![b'>'; required_moves.right]);
+
+        let length = ans.len();
+        ans.into_iter()
+            .permutations(length)
+            .unique()
+            .filter(|seq| self.would_be_valid_move_sequence(seq))
+            .map(|seq| seq.into_iter().chain([b'A']).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    }
+
+    fn is_in_valid_position(&self) -> bool {
+        self.keypad[(self.y, self.x)] != b'#'
+    }
+
+    fn would_be_valid_move_sequence(&self, moves: &[u8]) -> bool {
+        let mut simbot = self.clone();
+
+        for c in moves {
+            simbot.move_bot(*c);
+            if !simbot.is_in_valid_position() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn find(&self, needle: u8) -> (usize, usize) {
+        self.keypad
+            .indexed_iter()
+            .find(|(_, &v)| v == needle)
+            .map(|(pos, _)| pos)
+            .expect("can't find target")
+    }
+}
+
+fn minimum_cost_dir_bot(code: &[u8], depth: usize, cache: &mut Cache) -> u64 {
+    if depth == 0 {
+        return code.len() as u64;
+    } else if let Some(&cached_result) = cache.get(&(depth, code.to_vec())) {
+        return cached_result;
+    }
+
+    let dir_keypad: Array2<u8> =
+        Array2::from_shape_vec((2, 3), vec![b'#', b'^', b'A', b'<', b'v', b'>'])
+            .expect("static data");
+
+    let mut bot = Robot {
+        y: 0,
+        x: 2,
+        keypad: dir_keypad,
+    };
+
+    let result = bot
+        .type_code(code)
+        .into_iter()
+        .map(|way| {
+            let mut fragments = vec![];
+            let mut fragment = vec![];
+            for item in way {
+                fragment.push(item);
+                if item == b'A' {
+                    fragments.push(fragment);
+                    fragment = vec![];
+                }
+            }
+
+            fragments
+                .into_iter()
+                .map(|f| minimum_cost_dir_bot(&f, depth - 1, cache))
+                .sum()
+        })
+        .min()
+        .expect("no solution?");
+
+    cache.insert((depth, code.to_vec()), result);
+    result
+}
+
+fn minimum_cost<const DEPTH: usize>(code: &[u8], cache: &mut Cache) -> u64 {
+    let num_keypad: Array2<u8> = Array2::from_shape_vec(
+        (4, 3),
+        vec![
+            b'7', b'8', b'9', b'4', b'5', b'6', b'1', b'2', b'3', b'#', b'0', b'A',
+        ],
+    )
+    .expect("static data");
+
+    let mut numeric_robot = Robot {
+        y: 3,
+        x: 2,
+        keypad: num_keypad,
+    };
+
+    let numeric_bot_ways = numeric_robot.type_code(code);
+
+    numeric_bot_ways
+        .into_iter()
+        .map(|way| minimum_cost_dir_bot(&way, DEPTH, cache))
+        .min()
+        .expect("no solution?")
+}
+
+fn complexity_score<const DEPTH: usize>(code: &[u8], cache: &mut Cache) -> u64 {
+    let numeric_part = code
+        .iter()
+        .filter(|&c| c != &b'A')
+        .map(|c| c - b'0')
+        .fold(0, |acc, elem| acc * 10 + elem as u64);
+
+    minimum_cost::<DEPTH>(code, cache) * numeric_part
+}
+
+fn total_complexity<const DEPTH: usize>(codes: &[&[u8]]) -> u64 {
+    let mut cache = AHashMap::default();
+    codes
+        .iter()
+        .map(|code| complexity_score::<DEPTH>(code, &mut cache))
+        .sum()
+}
+
+/// The door codes to type.
+#[derive(Debug, Clone)]
+pub struct Input<'a> {
+    pub codes: Vec<&'a [u8]>,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Parsed<'a> = Input<'a>;
+    type P1 = u64;
+    type P2 = u64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let codes = raw_inp
+            .lines()
+            .map(
+                |line| match line.bytes().all(|b| b.is_ascii_digit() || b == b'A') {
+                    true => Ok(line.as_bytes()),
+                    false => Err(ParseError::new(raw_inp, line, "expected a door code")),
+                },
+            )
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { codes })
+    }
+
+    fn part1(Input { codes }: &Self::Parsed<'_>) -> u64 {
+        total_complexity::<2>(codes)
+    }
+
+    fn part2(Input { codes }: &Self::Parsed<'_>) -> u64 {
+        total_complexity::<25>(codes)
+    }
+}
+
+solution_fns!(Day21);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day21>(EXAMPLE_DATA).unwrap().0, 126384);
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day21>();
+    }
+}
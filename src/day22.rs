@@ -0,0 +1,128 @@
+use crate::parse_util::parse_num;
+use crate::{ParseError, Solution};
+use ahash::HashMapExt;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+
+fn next_secret(mut n: i64) -> i64 {
+    n = (n ^ (n << 6)) & 0xFFFFFF;
+    n = (n ^ (n >> 5)) & 0xFFFFFF;
+    n = (n ^ (n << 11)) & 0xFFFFFF;
+    n
+}
+
+fn sell(n: i64) -> FxHashMap<u32, i64> {
+    let mut n = n;
+    let mut map = FxHashMap::with_capacity(2000);
+
+    let mut diffs: u32 = 0;
+
+    for i in 0..2000 {
+        let next_n = next_secret(n);
+
+        let prev_ones = n % 10;
+        let next_ones = next_n % 10;
+
+        // who dis?
+        diffs <<= 8;
+        diffs |= (next_ones - prev_ones + 10) as u32;
+
+        n = next_n;
+
+        if i >= 3 {
+            map.entry(diffs).or_insert(next_ones);
+        }
+    }
+    map
+}
+
+/// Each buyer's initial secret number.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub secrets: Vec<i64>,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Parsed<'a> = Input;
+    type P1 = i64;
+    type P2 = i64;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let secrets = raw_inp
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| parse_num(raw_inp, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Input { secrets })
+    }
+
+    fn part1(Input { secrets }: &Self::Parsed<'_>) -> i64 {
+        secrets
+            .par_iter()
+            .map(|&n| (0..2000).fold(n, |n, _| next_secret(n)))
+            .sum()
+    }
+
+    fn part2(Input { secrets }: &Self::Parsed<'_>) -> i64 {
+        secrets
+            .par_iter()
+            .map(|&n| sell(n))
+            .reduce(
+                || FxHashMap::with_capacity(5000),
+                |mut acc, elem| {
+                    elem.into_iter().for_each(|(k, v)| {
+                        *acc.entry(k).or_insert(0) += v;
+                    });
+                    acc
+                },
+            )
+            .into_values()
+            .max()
+            .expect("no solution?")
+    }
+}
+
+solution_fns!(Day22);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA_P1: &str = "1
+10
+100
+2024
+";
+    const EXAMPLE_DATA_P2: &str = "1
+2
+3
+2024
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day22>(EXAMPLE_DATA_P1).unwrap().0, 37327623);
+        assert_eq!(solve::<Day22>(EXAMPLE_DATA_P2).unwrap().1, 23);
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA_P1);
+        assert_eq!(
+            solve::<Day22>(&raw_inp).unwrap(),
+            solve::<Day22>(EXAMPLE_DATA_P1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day22>();
+    }
+}
@@ -0,0 +1,193 @@
+use crate::parse_util::split_once;
+use crate::{ParseError, Solution};
+use ahash::{AHashMap, AHashSet};
+use itertools::Itertools;
+use std::sync::OnceLock;
+
+/// https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+fn bron_kerbosch<'a>(
+    mut r: Vec<&'a str>,
+    p: Vec<&'a str>,
+    mut x: Vec<&'a str>,
+    connections: &AHashMap<&'a str, AHashSet<&'a str>>,
+    cliques: &mut Vec<Vec<&'a str>>,
+) {
+    if p.is_empty() && x.is_empty() {
+        if r.len() >= 3 {
+            r.sort_unstable();
+            cliques.push(r);
+        }
+        return;
+    }
+
+    let u: &str = x.first().or(p.first()).expect("can't make pivot");
+    let nu = connections.get(u).expect("no connections?");
+
+    let mut reduced_p = p
+        .iter()
+        .filter(|itm| !nu.contains(*itm))
+        .collect::<Vec<_>>();
+
+    while let Some(v) = reduced_p.pop() {
+        let mut r_inner = r.clone();
+        r_inner.push(v);
+
+        let connected = connections.get(v).expect("no connections?");
+
+        let p_inner = p
+            .iter()
+            .filter(|&itm| connected.contains(itm))
+            .copied()
+            .collect::<Vec<_>>();
+
+        let x_inner = x
+            .iter()
+            .filter(|&itm| connected.contains(itm))
+            .copied()
+            .collect::<Vec<_>>();
+
+        bron_kerbosch(r_inner, p_inner, x_inner, connections, cliques);
+
+        x.push(v);
+    }
+}
+
+fn maximal_cliques<'a>(connections: &AHashMap<&'a str, AHashSet<&'a str>>) -> Vec<Vec<&'a str>> {
+    let mut groups: Vec<Vec<&str>> = vec![];
+    bron_kerbosch(
+        vec![],
+        connections.keys().copied().collect_vec(),
+        vec![],
+        connections,
+        &mut groups,
+    );
+    groups
+}
+
+/// The computers each computer is connected to.
+#[derive(Debug, Clone)]
+pub struct Input<'a> {
+    pub connections: AHashMap<&'a str, AHashSet<&'a str>>,
+    cliques: OnceLock<Vec<Vec<&'a str>>>,
+}
+
+impl<'a> Input<'a> {
+    /// The groups of three or more computers all connected to each other
+    /// that can't be made any larger, worked out once by whichever part needs
+    /// them first.
+    pub fn maximal_cliques(&self) -> &[Vec<&'a str>] {
+        self.cliques
+            .get_or_init(|| maximal_cliques(&self.connections))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Parsed<'a> = Input<'a>;
+    type P1 = usize;
+    type P2 = String;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let connections = raw_inp.lines().filter(|l| !l.is_empty()).try_fold(
+            AHashMap::<&str, AHashSet<&str>>::default(),
+            |mut acc, l| {
+                let (a, b) = split_once(raw_inp, l, "-")?;
+                acc.entry(a).or_default().insert(b);
+                acc.entry(b).or_default().insert(a);
+                Ok(acc)
+            },
+        )?;
+
+        Ok(Input {
+            connections,
+            cliques: OnceLock::new(),
+        })
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> usize {
+        input
+            .maximal_cliques()
+            .iter()
+            .flat_map(|g| g.iter().combinations(3))
+            .filter(|g| g.iter().any(|gi| gi.starts_with("t")))
+            .unique()
+            .count()
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> String {
+        input
+            .maximal_cliques()
+            .iter()
+            .max_by_key(|g| g.len())
+            .map(|g| g.iter().join(","))
+            .expect("no p2 solution?")
+    }
+}
+
+solution_fns!(Day23);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            solve::<Day23>(EXAMPLE_DATA).unwrap(),
+            (7, "co,de,ka,ta".to_string())
+        );
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA);
+        assert_eq!(
+            solve::<Day23>(&raw_inp).unwrap(),
+            solve::<Day23>(EXAMPLE_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day23>();
+    }
+}
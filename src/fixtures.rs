@@ -0,0 +1,73 @@
+use crate::input::InputSource;
+use crate::manifest::Manifest;
+use crate::{registry, solve, Answer, Solution};
+use std::fs;
+use std::io::{self, ErrorKind, Write};
+use std::path::PathBuf;
+
+fn input_dir() -> PathBuf {
+    std::env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+
+/// Straight to stderr, as the test harness captures `eprintln!`.
+fn skip(day: u8, why: &str) {
+    let _ = writeln!(
+        io::stderr(),
+        "skipping day {} real input test: {}",
+        day,
+        why
+    );
+}
+
+/// Reads the real input for `day` at test time, from `AOC_INPUT_DIR` or the
+/// `inputs` submodule.
+///
+/// The submodule is private, so if the input isn't there this prints why the
+/// test is being skipped and returns `None` rather than failing. Any other
+/// error reading it fails the test.
+pub fn real_input(day: u8) -> Option<String> {
+    let source = InputSource::for_day(&input_dir(), registry::get(day)?, false);
+
+    match source.read() {
+        Ok(inp) => Some(inp),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            skip(day, &format!("can't read {}: {}", source, e));
+            None
+        }
+        Err(e) => panic!("can't read {}: {}", source, e),
+    }
+}
+
+/// Solves the real input for `S`'s day, if there is one, and checks the
+/// answers against those expected in the answers file: `AOC_ANSWERS`, or
+/// `answers.tsv` in the repository. Skips, rather than failing, if the answers
+/// file has nothing for the input.
+pub fn check_real_answers<S: Solution>() {
+    let Some(real_data) = real_input(S::DAY) else {
+        return;
+    };
+
+    let path = std::env::var_os("AOC_ANSWERS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.tsv"));
+    let manifest = fs::read_to_string(&path)
+        .map(|raw| Manifest::parse(&raw))
+        .unwrap_or_else(|e| panic!("can't read {}: {}", path.display(), e))
+        .unwrap_or_else(|e| panic!("can't parse {}: {}", path.display(), e));
+
+    let key = format!("real/{}", registry::get(S::DAY).unwrap().input_name());
+    let Some((p1, p2)) = manifest.get(&key) else {
+        skip(S::DAY, &format!("no expected answers for {}", key));
+        return;
+    };
+
+    let (a1, a2) = solve::<S>(&real_data).unwrap();
+    let (a1, a2): (Answer, Answer) = (a1.into(), a2.into());
+    // Compared as written, like `Manifest::check`.
+    assert_eq!(
+        (a1.to_string(), a2.to_string()),
+        (p1.to_string(), p2.to_string())
+    );
+}
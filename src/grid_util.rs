@@ -1,33 +1,469 @@
+use crate::bitvec_set::BitVecSet2D;
+use crate::direction::Direction;
+use crate::ParseError;
 use ndarray::Array2;
+use std::fmt::{self, Display};
+use std::ops::{Deref, DerefMut};
 
-pub fn make_byte_grid(raw_inp: &str) -> Array2<u8> {
-    let columns = raw_inp
-        .trim()
-        .bytes()
-        .position(|c| c == b'\n')
-        .expect("can't get column count");
-
-    Array2::from_shape_vec(
-        ((raw_inp.trim().len() + 1) / (columns + 1), columns),
-        raw_inp.bytes().filter(|&x| x != b'\n').collect(),
-    )
-    .expect("can't make array")
-}
-
-pub fn make_bool_grid<const TRUE_CHAR: u8>(raw_inp: &str) -> Array2<bool> {
-    let columns = raw_inp
-        .trim()
-        .bytes()
-        .position(|c| c == b'\n')
-        .expect("can't get column count");
-
-    Array2::from_shape_vec(
-        ((raw_inp.trim().len() + 1) / (columns + 1), columns),
-        raw_inp
-            .bytes()
-            .filter(|&x| x != b'\n')
-            .map(|b| b == TRUE_CHAR)
-            .collect(),
-    )
-    .expect("can't make array")
+/// Offsets to the 4 orthogonal neighbours, as `(row, column)`.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// Offsets to all 8 neighbours, including diagonals.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// A 2D grid indexed by `(row, column)`.
+///
+/// Derefs to the underlying `Array2`, and adds the bounds-checked stepping and
+/// searching that grid puzzles otherwise hand-roll.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn into_inner(self) -> Array2<T> {
+        self.data
+    }
+
+    /// Moves `pos` by a signed offset, if that stays inside the grid.
+    pub fn step(&self, pos: (usize, usize), offset: (isize, isize)) -> Option<(usize, usize)> {
+        let next = (
+            pos.0.checked_add_signed(offset.0)?,
+            pos.1.checked_add_signed(offset.1)?,
+        );
+        (next.0 < self.nrows() && next.1 < self.ncols()).then_some(next)
+    }
+
+    /// The cell at a signed offset from `pos`, if inside the grid.
+    pub fn get_offset(&self, pos: (usize, usize), offset: (isize, isize)) -> Option<&T> {
+        self.step(pos, offset).map(|next| &self.data[next])
+    }
+
+    /// Orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    /// Every position, in row-major order.
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (rows, cols) = self.dim();
+        (0..rows).flat_map(move |r| (0..cols).map(move |c| (r, c)))
+    }
+
+    /// The first position, in row-major order, whose cell matches.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.indexed_iter()
+            .find(|(_, v)| pred(v))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Every position whose cell matches, in row-major order.
+    pub fn positions<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.indexed_iter()
+            .filter(move |(_, v)| pred(v))
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first position, in row-major order, holding `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)> {
+        self.position(|v| v == value)
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(data: Array2<T>) -> Self {
+        Grid { data }
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = Array2<T>;
+
+    fn deref(&self) -> &Array2<T> {
+        &self.data
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Array2<T> {
+        &mut self.data
+    }
+}
+
+/// Splits a grid into its rows, ignoring a UTF-8 BOM, `\r\n` line endings and
+/// trailing blank lines, and checks they are all as wide as the first.
+fn grid_rows(raw_inp: &str) -> Result<Vec<&str>, ParseError> {
+    let inp = raw_inp
+        .strip_prefix('\u{feff}')
+        .unwrap_or(raw_inp)
+        .trim_end();
+    if inp.is_empty() {
+        return Err(ParseError::missing(raw_inp, "empty grid"));
+    }
+
+    let rows = inp.lines().collect::<Vec<_>>();
+    let columns = rows[0].len();
+
+    if let Some((r, row)) = rows
+        .iter()
+        .enumerate()
+        .find(|(_, row)| row.len() != columns)
+    {
+        return Err(ParseError::new(
+            raw_inp,
+            row,
+            format!(
+                "grid isn't rectangular, row {} is {} wide but row 1 is {}",
+                r + 1,
+                row.len(),
+                columns
+            ),
+        ));
+    }
+
+    Ok(rows)
+}
+
+pub fn make_byte_grid(raw_inp: &str) -> Result<Array2<u8>, ParseError> {
+    make_grid_by(raw_inp, |_, b| Ok(b)).map(Grid::into_inner)
+}
+
+pub fn make_bool_grid<const TRUE_CHAR: u8>(raw_inp: &str) -> Result<Array2<bool>, ParseError> {
+    make_grid_by(raw_inp, |_, b| Ok(b == TRUE_CHAR)).map(Grid::into_inner)
+}
+
+/// A grid and the positions of its markers, see [`make_grid_with_markers`].
+pub type MarkedGrid<T, const N: usize> = (Grid<T>, [(usize, usize); N]);
+
+/// Parses a grid of tiles, converting each character with `T::try_from`, e.g.
+/// into a day's own tile enum. Characters it rejects are reported as unknown.
+pub fn make_grid<T: TryFrom<u8>>(raw_inp: &str) -> Result<Grid<T>, ParseError> {
+    make_grid_by(raw_inp, |_, b| T::try_from(b).map_err(|_| "unknown tile"))
+}
+
+/// Like [`make_grid`], but also finds the cell holding each of `markers`, such
+/// as a start `S` and end `E`. Each marker must appear exactly once, and its
+/// cell is parsed as if it held `floor` instead.
+pub fn make_grid_with_markers<T: TryFrom<u8>, const N: usize>(
+    raw_inp: &str,
+    markers: [u8; N],
+    floor: u8,
+) -> Result<MarkedGrid<T, N>, ParseError> {
+    let mut found = [None; N];
+
+    let grid = make_grid_by(raw_inp, |pos, b| {
+        let b = match markers.iter().position(|&m| m == b) {
+            Some(i) if found[i].replace(pos).is_some() => return Err("marker appears twice"),
+            Some(_) => floor,
+            None => b,
+        };
+        T::try_from(b).map_err(|_| "unknown tile")
+    })?;
+
+    let mut positions = [(0, 0); N];
+    for ((pos, found), marker) in positions.iter_mut().zip(found).zip(markers) {
+        *pos = found.ok_or_else(|| {
+            ParseError::missing(raw_inp, format!("no {} in grid", marker as char))
+        })?;
+    }
+
+    Ok((grid, positions))
+}
+
+fn make_grid_by<T>(
+    raw_inp: &str,
+    mut tile: impl FnMut((usize, usize), u8) -> Result<T, &'static str>,
+) -> Result<Grid<T>, ParseError> {
+    let rows = grid_rows(raw_inp)?;
+    let shape = (rows.len(), rows[0].len());
+    let mut tiles = Vec::with_capacity(shape.0 * shape.1);
+
+    for (r, line) in rows.into_iter().enumerate() {
+        for (c, b) in line.bytes().enumerate() {
+            match tile((r, c), b) {
+                Ok(t) => tiles.push(t),
+                Err(reason) => {
+                    let start = (0..=c)
+                        .rev()
+                        .find(|&i| line.is_char_boundary(i))
+                        .unwrap_or(0);
+                    let len = line[start..].chars().next().map_or(1, char::len_utf8);
+                    return Err(ParseError::new(raw_inp, &line[start..start + len], reason));
+                }
+            }
+        }
+    }
+
+    Ok(Array2::from_shape_vec(shape, tiles)
+        .expect("rows are all the same width")
+        .into())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layer {
+    Base,
+    Highlight,
+    Path,
+    Override,
+}
+
+impl Layer {
+    fn ansi(self) -> Option<&'static str> {
+        match self {
+            Layer::Base => None,
+            Layer::Highlight => Some("\x1b[1;33m"),
+            Layer::Path => Some("\x1b[36m"),
+            Layer::Override => Some("\x1b[1;31m"),
+        }
+    }
+}
+
+/// A grid rendered back to text for debugging, one line per row.
+///
+/// Overlays are drawn in the order they're added, so later ones win. Positions
+/// outside the grid are ignored.
+#[derive(Debug, Clone)]
+pub struct Rendered {
+    cells: Array2<(u8, Layer)>,
+    colour: bool,
+}
+
+/// Grids that can be rendered as text.
+pub trait Render {
+    fn render(&self) -> Rendered;
+}
+
+impl Render for Array2<u8> {
+    fn render(&self) -> Rendered {
+        Rendered::from_fn(self.dim(), |pos| self[pos])
+    }
+}
+
+impl Render for Array2<bool> {
+    fn render(&self) -> Rendered {
+        Rendered::from_fn(self.dim(), |pos| if self[pos] { b'#' } else { b'.' })
+    }
+}
+
+impl Render for BitVecSet2D {
+    fn render(&self) -> Rendered {
+        Rendered::from_fn(
+            self.bounds,
+            |pos| {
+                if self.contains(&pos) {
+                    b'#'
+                } else {
+                    b'.'
+                }
+            },
+        )
+    }
+}
+
+impl Rendered {
+    /// Renders a grid of some other type, e.g. a day's own tiles.
+    pub fn from_fn(shape: (usize, usize), f: impl Fn((usize, usize)) -> u8) -> Rendered {
+        Rendered {
+            cells: Array2::from_shape_fn(shape, |pos| (f(pos), Layer::Base)),
+            colour: false,
+        }
+    }
+
+    /// Whether to colour overlays with ANSI escape codes.
+    pub fn colour(mut self, colour: bool) -> Rendered {
+        self.colour = colour;
+        self
+    }
+
+    /// Marks some positions. They keep their character when coloured, and
+    /// are shown as `O` otherwise.
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = (usize, usize)>) -> Rendered {
+        for pos in positions {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.1 = Layer::Highlight;
+            }
+        }
+        self
+    }
+
+    /// Draws a path as arrows from each position to the next, or `*` where the
+    /// next position isn't adjacent. The last position keeps its character.
+    pub fn path(mut self, path: &[(usize, usize)]) -> Rendered {
+        for (i, &pos) in path.iter().enumerate() {
+            let ch = match path.get(i + 1) {
+                Some(&next) => Direction::ALL
+                    .into_iter()
+                    .find(|d| d.step(pos) == next)
+                    .map_or(b'*', Direction::arrow),
+                None => self.cells.get(pos).map_or(b'*', |cell| cell.0),
+            };
+            if let Some(cell) = self.cells.get_mut(pos) {
+                *cell = (ch, Layer::Path);
+            }
+        }
+        self
+    }
+
+    /// Overrides the character at one position.
+    pub fn set(mut self, pos: (usize, usize), ch: u8) -> Rendered {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            *cell = (ch, Layer::Override);
+        }
+        self
+    }
+}
+
+impl Display for Rendered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            for &(ch, layer) in row {
+                match layer.ansi() {
+                    Some(code) if self.colour => write!(f, "{}{}\x1b[0m", code, ch as char)?,
+                    _ if layer == Layer::Highlight => write!(f, "O")?,
+                    _ => write!(f, "{}", ch as char)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        make_byte_grid("S.#\n..E\n").unwrap().into()
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = grid();
+        assert_eq!(grid.step((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((1, 2), (0, 1)), None);
+        assert_eq!(grid.get_offset((1, 1), (-1, 1)), Some(&b'#'));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 1)).collect::<Vec<_>>(),
+            [(0, 2), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = grid();
+        assert_eq!(grid.find(&b'E'), Some((1, 2)));
+        assert_eq!(grid.find(&b'@'), None);
+        assert_eq!(grid.positions(|&v| v == b'.').count(), 3);
+        assert_eq!(grid.indices().nth(4), Some((1, 1)));
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Floor,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = u8;
+
+        fn try_from(b: u8) -> Result<Tile, u8> {
+            match b {
+                b'#' => Ok(Tile::Wall),
+                b'.' => Ok(Tile::Floor),
+                _ => Err(b),
+            }
+        }
+    }
+
+    #[test]
+    fn test_make_grid() {
+        let (grid, [start, end]) =
+            make_grid_with_markers::<Tile, 2>("S.#\n..E\n", [b'S', b'E'], b'.').unwrap();
+        assert_eq!((start, end), ((0, 0), (1, 2)));
+        assert_eq!(grid[start], Tile::Floor);
+        assert_eq!(grid.find(&Tile::Wall), Some((0, 2)));
+
+        let err = make_grid::<Tile>("..#\n.x.\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let err = make_grid_with_markers::<Tile, 1>("S.\n.S\n", [b'S'], b'.').unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert!(make_grid_with_markers::<Tile, 1>("#.\n", [b'S'], b'.').is_err());
+    }
+
+    #[test]
+    fn test_normalise() {
+        let expected = make_byte_grid("#.\n.#\n").unwrap();
+        assert_eq!(make_byte_grid("#.\r\n.#\r\n").unwrap(), expected);
+        assert_eq!(make_byte_grid("\u{feff}#.\n.#").unwrap(), expected);
+        assert_eq!(make_byte_grid("#.\n.#\n\n  \n").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_ragged() {
+        let err = make_byte_grid("#..\n.#\n..#\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.reason,
+            "grid isn't rectangular, row 2 is 2 wide but row 1 is 3"
+        );
+
+        assert_eq!(make_byte_grid("\n\n").unwrap_err().reason, "empty grid");
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = make_byte_grid("S..\n.#.\n..E\n").unwrap();
+        assert_eq!(grid.render().to_string(), "S..\n.#.\n..E\n");
+
+        let path = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)];
+        assert_eq!(
+            grid.render()
+                .highlight([(1, 0), (9, 9)])
+                .path(&path)
+                .set((1, 1), b'X')
+                .to_string(),
+            ">>v\nOXv\n..E\n"
+        );
+        assert_eq!(
+            grid.render().set((0, 1), b'X').colour(true).to_string(),
+            "S\x1b[1;31mX\x1b[0m.\n.#.\n..E\n"
+        );
+
+        let mut set = BitVecSet2D::new((2, 2));
+        set.insert((1, 0));
+        assert_eq!(set.render().to_string(), "..\n#.\n");
+    }
 }
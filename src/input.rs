@@ -0,0 +1,189 @@
+use crate::registry::Day;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets an `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Interprets an `--input` argument that may name a directory, which
+    /// stands for every file directly inside it, sorted by name. Hidden files
+    /// are skipped.
+    pub fn expand_arg(arg: &str) -> io::Result<Vec<InputSource>> {
+        let path = Path::new(arg);
+        if !path.is_dir() {
+            return Ok(vec![InputSource::from_arg(arg)]);
+        }
+
+        let mut files = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .filter(|path| {
+                path.as_ref().map_or(true, |path| {
+                    path.is_file()
+                        && !path
+                            .file_name()
+                            .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        files.sort();
+
+        Ok(files.into_iter().map(InputSource::File).collect())
+    }
+
+    /// The conventional input for `day` under `input_dir`, i.e.
+    /// `real/2024_NN` or `examples/2024_NN`.
+    pub fn for_day(input_dir: &Path, day: &Day, example: bool) -> InputSource {
+        let kind = if example { "examples" } else { "real" };
+        InputSource::File(input_dir.join(kind).join(day.input_name()))
+    }
+
+    /// The key for this input in a [`crate::manifest::Manifest`]: its path
+    /// relative to `input_dir`, or as given if it's elsewhere. Both paths are
+    /// resolved first where they exist, so e.g. `./inputs/real/2024_06` or an
+    /// absolute path still count as inside `inputs`. Stdin has none.
+    pub fn manifest_key(&self, input_dir: &Path) -> Option<String> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::File(path) => {
+                let resolved = match (fs::canonicalize(path), fs::canonicalize(input_dir)) {
+                    (Ok(path), Ok(input_dir)) => {
+                        path.strip_prefix(input_dir).ok().map(Path::to_path_buf)
+                    }
+                    _ => None,
+                };
+                let key = resolved
+                    .or_else(|| path.strip_prefix(input_dir).ok().map(Path::to_path_buf))
+                    .unwrap_or_else(|| path.clone());
+                Some(key.to_string_lossy().replace('\\', "/"))
+            }
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut inp = String::new();
+                io::stdin().read_to_string(&mut inp)?;
+                Ok(inp)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+/// Expands every `--input` argument, see [`InputSource::expand_arg`].
+pub fn expand_args(args: &[String]) -> io::Result<Vec<InputSource>> {
+    args.iter()
+        .map(|arg| InputSource::expand_arg(arg))
+        .collect::<io::Result<Vec<_>>>()
+        .map(|sources| sources.concat())
+}
+
+/// Whether `--input` arguments ask for a batch: more than one input, or a
+/// directory of them.
+pub fn is_batch(args: &[String]) -> bool {
+    args.len() > 1 || args.iter().any(|arg| Path::new(arg).is_dir())
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_sources() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+
+        let day = registry::get(6).unwrap();
+        assert_eq!(
+            InputSource::for_day(Path::new("shared"), day, false),
+            InputSource::File(PathBuf::from("shared/real/2024_06"))
+        );
+        assert_eq!(
+            InputSource::for_day(Path::new("shared"), day, true),
+            InputSource::File(PathBuf::from("shared/examples/2024_06"))
+        );
+    }
+
+    #[test]
+    fn test_expand_arg() {
+        let dir = std::env::temp_dir().join(format!("aoc-expand-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for name in ["bob", "alice", ".hidden"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let expanded = InputSource::expand_arg(dir.to_str().unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            expanded.unwrap(),
+            vec![
+                InputSource::File(dir.join("alice")),
+                InputSource::File(dir.join("bob"))
+            ]
+        );
+        assert_eq!(
+            InputSource::expand_arg("-").unwrap(),
+            vec![InputSource::Stdin]
+        );
+    }
+
+    #[test]
+    fn test_manifest_key() {
+        let day = registry::get(6).unwrap();
+        let dir = Path::new("inputs");
+
+        assert_eq!(
+            InputSource::for_day(dir, day, false).manifest_key(dir),
+            Some("real/2024_06".to_string())
+        );
+        assert_eq!(
+            InputSource::from_arg("/tmp/mine").manifest_key(dir),
+            Some("/tmp/mine".to_string())
+        );
+        assert_eq!(InputSource::Stdin.manifest_key(dir), None);
+    }
+
+    #[test]
+    fn test_manifest_key_resolved() {
+        let dir = std::env::temp_dir().join(format!("aoc-key-{}", std::process::id()));
+        fs::create_dir_all(dir.join("real")).unwrap();
+        fs::write(dir.join("real").join("2024_06"), "").unwrap();
+
+        let relative = dir
+            .join(".")
+            .join("real")
+            .join("..")
+            .join("real")
+            .join("2024_06");
+        let key = InputSource::File(relative).manifest_key(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(key, Some("real/2024_06".to_string()));
+    }
+}
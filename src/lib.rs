@@ -1,9 +1,269 @@
+pub use answer::Answer;
 pub use clap::Parser;
+pub use parse_util::ParseError;
+
+use batch::Batch;
+use bench::BenchArgs;
+use clap::{Args, ValueEnum};
+use input::InputSource;
+use manifest::Manifest;
+use registry::Day;
+use report::{Format, Timings};
+use std::borrow::Cow;
+use std::io;
+use std::path::PathBuf;
+use std::time::Instant;
+use threads::ThreadArgs;
+
+pub mod answer;
+pub mod batch;
+pub mod bench;
 pub mod bitvec_set;
+pub mod direction;
+#[cfg(test)]
+mod fixtures;
 pub mod grid_util;
+pub mod input;
+pub mod manifest;
+pub mod parse_util;
+pub mod pathfinding;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod sparse_grid;
+pub mod threads;
+
+/// Adds `parse`, `part1` and `part2` functions to a day's module, so callers
+/// can use e.g. `day06::parse` without going through [`Solution`].
+macro_rules! solution_fns {
+    ($day:ty) => {
+        /// Parses the puzzle input, which must have LF line endings.
+        pub fn parse(
+            raw_inp: &str,
+        ) -> Result<<$day as $crate::Solution>::Parsed<'_>, $crate::ParseError> {
+            <$day as $crate::Solution>::parse(raw_inp)
+                .map_err(|e| e.with_day(<$day as $crate::Solution>::DAY))
+        }
+
+        /// Solves part 1 from the parsed input.
+        pub fn part1(
+            input: &<$day as $crate::Solution>::Parsed<'_>,
+        ) -> <$day as $crate::Solution>::P1 {
+            <$day as $crate::Solution>::part1(input)
+        }
+
+        /// Solves part 2 from the parsed input.
+        pub fn part2(
+            input: &<$day as $crate::Solution>::Parsed<'_>,
+        ) -> <$day as $crate::Solution>::P2 {
+            <$day as $crate::Solution>::part2(input)
+        }
+    };
+}
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Options shared by the runners: which inputs to read, what to solve and
+/// how to report it.
+#[derive(Args)]
+pub struct RunArgs {
+    /// Input file, or `-` to read from stdin. Several inputs, or a directory
+    /// of them, are solved as a batch
+    #[clap(short, long, num_args = 1.., conflicts_with = "example")]
+    pub input: Vec<String>,
+
+    /// Read the example input instead of the real one
+    #[clap(long)]
+    pub example: bool,
+
+    /// Directory containing `real/` and `examples/` inputs
+    #[clap(long, env = "AOC_INPUT_DIR", default_value = "inputs")]
+    pub input_dir: PathBuf,
+
+    /// How to print the answers
+    #[clap(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Which parts to solve
+    #[clap(long, value_enum, default_value_t)]
+    pub part: Parts,
+
+    /// Also print how long parsing and each part took
+    #[clap(long)]
+    pub timings: bool,
+
+    /// Compare the answers against those expected in the answers file
+    #[clap(long)]
+    pub check: bool,
+
+    /// Expected answers, keyed by input file relative to the input directory
+    #[clap(long, env = "AOC_ANSWERS", default_value = "answers.tsv")]
+    pub answers: PathBuf,
+
+    #[clap(flatten)]
+    pub bench: BenchArgs,
+
+    #[clap(flatten)]
+    pub threads: ThreadArgs,
+}
+
+impl RunArgs {
+    /// Where to read `day`'s inputs from: `--input` if given, otherwise the
+    /// conventional file in the input directory.
+    pub fn input_sources(&self, day: &Day) -> io::Result<Vec<InputSource>> {
+        match self.input.is_empty() {
+            true => Ok(vec![InputSource::for_day(
+                &self.input_dir,
+                day,
+                self.example,
+            )]),
+            false => input::expand_args(&self.input),
+        }
+    }
+
+    /// Whether `--input` asks for a batch, see [`input::is_batch`].
+    pub fn is_batch(&self) -> bool {
+        input::is_batch(&self.input)
+    }
+
+    /// A batch solving `day` with these options.
+    pub fn batch<'a>(&'a self, day: &'a Day, manifest: Option<&'a Manifest>) -> Batch<'a> {
+        Batch {
+            day,
+            parts: self.part,
+            bench: self.bench,
+            threads: self.threads,
+            manifest,
+            input_dir: &self.input_dir,
+        }
+    }
+}
 
+/// Solves one day of Advent of Code 2024, that of the binary's name.
 #[derive(Parser)]
 pub struct Cli {
-    #[clap(short, long)]
-    pub input: String,
+    /// Day whose input to read from the input directory
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    #[clap(flatten)]
+    pub run: RunArgs,
+}
+
+/// A single day's puzzle, split into parsing and the two parts so that every
+/// day can be driven by the same runner.
+///
+/// Work that both parts need is cached on the parsed input by whichever part
+/// runs first, rather than done while parsing, so that it's timed as part of
+/// solving.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed<'a>;
+    type P1: Into<Answer>;
+    type P2: Into<Answer>;
+
+    fn parse(raw_inp: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::P1;
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::P2;
+}
+
+/// Which parts of a puzzle to solve. Parts that aren't asked for aren't run
+/// at all, and have no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    pub fn part1(self) -> bool {
+        self != Parts::Two
+    }
+
+    pub fn part2(self) -> bool {
+        self != Parts::One
+    }
+}
+
+/// Puzzle input with any CRLF line endings made LF, so that days can split it
+/// into sections on blank lines. Borrowed unless there were any.
+fn normalise_newlines(raw_inp: &str) -> Cow<'_, str> {
+    match raw_inp.contains('\r') {
+        true => Cow::Owned(raw_inp.replace("\r\n", "\n")),
+        false => Cow::Borrowed(raw_inp),
+    }
+}
+
+/// Parses the input once and solves both parts from it.
+pub fn solve<S: Solution>(raw_inp: &str) -> Result<(S::P1, S::P2), ParseError> {
+    let raw_inp = normalise_newlines(raw_inp);
+    let parsed = S::parse(&raw_inp).map_err(|e| e.with_day(S::DAY))?;
+    Ok((S::part1(&parsed), S::part2(&parsed)))
+}
+
+/// The answers to whichever parts were solved.
+pub type PartAnswers<S> = (Option<<S as Solution>::P1>, Option<<S as Solution>::P2>);
+
+/// Solves only the requested parts, returning `None` for the others.
+pub fn solve_parts<S: Solution>(raw_inp: &str, parts: Parts) -> Result<PartAnswers<S>, ParseError> {
+    let raw_inp = normalise_newlines(raw_inp);
+    let parsed = S::parse(&raw_inp).map_err(|e| e.with_day(S::DAY))?;
+    Ok((
+        parts.part1().then(|| S::part1(&parsed)),
+        parts.part2().then(|| S::part2(&parsed)),
+    ))
+}
+
+/// Like [`solve_parts`], but also times parsing and each part separately.
+pub fn solve_timed<S: Solution>(
+    raw_inp: &str,
+    parts: Parts,
+) -> Result<(PartAnswers<S>, Timings), ParseError> {
+    let start = Instant::now();
+    let raw_inp = normalise_newlines(raw_inp);
+    let parsed = S::parse(&raw_inp).map_err(|e| e.with_day(S::DAY))?;
+    let parsed_at = Instant::now();
+    let p1 = parts.part1().then(|| S::part1(&parsed));
+    let p1_at = Instant::now();
+    let p2 = parts.part2().then(|| S::part2(&parsed));
+    let p2_at = Instant::now();
+
+    Ok((
+        (p1, p2),
+        Timings {
+            total: p2_at - start,
+            parse: parsed_at - start,
+            part1: p1_at - parsed_at,
+            part2: p2_at - p1_at,
+        },
+    ))
 }
@@ -0,0 +1,191 @@
+use crate::parse_util::split_once;
+use crate::report::Report;
+use crate::{Answer, ParseError, Parts};
+use ahash::AHashMap;
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Display};
+
+/// Expected answers, keyed by input file relative to the input directory,
+/// e.g. `real/2024_06`.
+///
+/// Stored as TSV: the input, then part 1 and part 2, with an empty part where
+/// there is no answer. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+    expected: AHashMap<String, (Answer, Answer)>,
+}
+
+/// The outcome of comparing a day's answers against the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail {
+        expected: (Answer, Answer),
+    },
+    /// The manifest has nothing for this input.
+    Missing,
+}
+
+impl Check {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail { .. } => "fail",
+            Check::Missing => "missing",
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { expected } => {
+                write!(f, "FAIL, expected {} and {}", expected.0, expected.1)
+            }
+            Check::Missing => write!(f, "no expected answers for this input"),
+        }
+    }
+}
+
+impl Serialize for Check {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.status())
+    }
+}
+
+impl Manifest {
+    pub fn parse(raw_inp: &str) -> Result<Manifest, ParseError> {
+        let expected = raw_inp
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| line.trim_end_matches('\r'))
+            .map(|line| {
+                let (input, answers) = split_once(raw_inp, line, "\t")?;
+                let (p1, p2) = split_once(raw_inp, answers, "\t")?;
+
+                if p2.contains('\t') {
+                    return Err(ParseError::new(raw_inp, p2, "expected 3 columns"));
+                }
+
+                let parse = |s: &str| s.parse::<Answer>().unwrap_or_else(|e| match e {});
+                Ok((input.to_string(), (parse(p1), parse(p2))))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manifest { expected })
+    }
+
+    pub fn get(&self, input: &str) -> Option<&(Answer, Answer)> {
+        self.expected.get(input)
+    }
+
+    /// Compares a day's answers against those expected for `input`, if known.
+    /// Only the parts that were solved are compared.
+    ///
+    /// Answers are compared as written, as the manifest can't tell a text
+    /// answer like `5` from a number.
+    pub fn check(&self, input: Option<&str>, report: &Report, parts: Parts) -> Check {
+        match input.and_then(|input| self.get(input)) {
+            None => Check::Missing,
+            Some((p1, p2))
+                if (!parts.part1() || p1.to_string() == report.part1.to_string())
+                    && (!parts.part2() || p2.to_string() == report.part2.to_string()) =>
+            {
+                Check::Pass
+            }
+            Some(expected) => Check::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "# input\tpart1\tpart2
+real/2024_17\t1,0,2,0,5,7,2,1,3\t265652340990875
+
+real/2024_25\t3196\t
+";
+
+    #[test]
+    fn test_parse() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+
+        assert_eq!(
+            manifest.get("real/2024_17"),
+            Some(&(
+                Answer::Text("1,0,2,0,5,7,2,1,3".to_string()),
+                Answer::Integer(265652340990875)
+            ))
+        );
+        assert_eq!(
+            manifest.get("real/2024_25"),
+            Some(&(Answer::Integer(3196), Answer::None))
+        );
+        assert_eq!(manifest.get("real/2024_01"), None);
+    }
+
+    #[test]
+    fn test_check_parts() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        let report = Report {
+            day: 17,
+            part1: Answer::None,
+            part2: Answer::Integer(265652340990875),
+            timings: Default::default(),
+            bench: None,
+            check: None,
+        };
+
+        let check = |parts| manifest.check(Some("real/2024_17"), &report, parts);
+        assert_eq!(check(Parts::Two), Check::Pass);
+        assert!(matches!(check(Parts::Both), Check::Fail { .. }));
+        assert_eq!(
+            manifest.check(Some("real/2024_01"), &report, Parts::Two),
+            Check::Missing
+        );
+    }
+
+    #[test]
+    fn test_crlf() {
+        let manifest = Manifest::parse(&MANIFEST.replace('\n', "\r\n")).unwrap();
+        assert_eq!(
+            manifest.get("real/2024_25"),
+            Some(&(Answer::Integer(3196), Answer::None))
+        );
+
+        let manifest = Manifest::parse("real/2024_01\t1\t2\r").unwrap();
+        assert_eq!(
+            manifest.get("real/2024_01"),
+            Some(&(Answer::Integer(1), Answer::Integer(2)))
+        );
+    }
+
+    #[test]
+    fn test_check_numeric_text() {
+        let manifest = Manifest::parse("real/2024_17\t5\t\n").unwrap();
+        let report = Report {
+            day: 17,
+            part1: Answer::Text("5".to_string()),
+            part2: Answer::None,
+            timings: Default::default(),
+            bench: None,
+            check: None,
+        };
+
+        assert_eq!(
+            manifest.check(Some("real/2024_17"), &report, Parts::Both),
+            Check::Pass
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Manifest::parse("real/2024_01\t1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 14));
+    }
+}
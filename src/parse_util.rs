@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// An error from parsing puzzle input, pointing at the offending text.
+///
+/// Positions are worked out from where the offending slice sits inside the
+/// raw input, so parsers only need to hand over the `&str` they choked on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day being parsed, filled in once the error reaches [`crate::solve`].
+    pub day: Option<u8>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The first line of the offending text.
+    pub text: String,
+    pub reason: String,
+}
+
+/// Returns the 1-based (line, column) at which `part` starts, if `part` is a
+/// slice of `raw_inp`.
+fn locate(raw_inp: &str, part: &str) -> Option<(usize, usize)> {
+    let offset = (part.as_ptr() as usize).checked_sub(raw_inp.as_ptr() as usize)?;
+    if offset > raw_inp.len() {
+        return None;
+    }
+
+    let before = &raw_inp[..offset];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    Some((
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    ))
+}
+
+impl ParseError {
+    /// Creates an error for `offending`, which must be a slice of `raw_inp`.
+    pub fn new(raw_inp: &str, offending: &str, reason: impl Into<String>) -> ParseError {
+        let (line, column) = locate(raw_inp, offending).unwrap_or((0, 0));
+        debug_assert!(line != 0, "offending text is not part of the input");
+
+        ParseError {
+            day: None,
+            line,
+            column,
+            text: offending.lines().next().unwrap_or("").to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error at a known position, e.g. a cell of a grid.
+    pub fn at(line: usize, column: usize, text: &str, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error for something that was never found, pointing at the
+    /// end of the input.
+    pub fn missing(raw_inp: &str, reason: impl Into<String>) -> ParseError {
+        ParseError::new(raw_inp, &raw_inp[raw_inp.len()..], reason)
+    }
+
+    /// Moves an error produced while parsing `part` on its own (e.g. by a
+    /// `FromStr` impl) so that its position is relative to all of `raw_inp`.
+    pub fn within(mut self, raw_inp: &str, part: &str) -> ParseError {
+        if let Some((line, column)) = locate(raw_inp, part) {
+            if self.line == 1 {
+                self.column += column - 1;
+            }
+            self.line += line - 1;
+        }
+        self
+    }
+
+    pub fn with_day(mut self, day: u8) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " at {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s` as a number, pointing at it on failure.
+pub fn parse_num<T: FromStr>(raw_inp: &str, s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(raw_inp, s, "invalid number"))
+}
+
+/// Splits `s` around the first `delim`, pointing at `s` if there isn't one.
+pub fn split_once<'a>(
+    raw_inp: &str,
+    s: &'a str,
+    delim: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::new(raw_inp, s, format!("expected {:?}", delim)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "12 34\n56 x8\n";
+
+    #[test]
+    fn test_position() {
+        let err = parse_num::<i32>(INPUT, &INPUT[9..11]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "x8");
+
+        let err = ParseError::missing(INPUT, "no end");
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn test_within() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = parse_num::<i32>(line, &line[3..]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+
+        let err = err.within(INPUT, line);
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = split_once(INPUT, &INPUT[6..11], ",")
+            .unwrap_err()
+            .with_day(1);
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 1: expected \",\" at \"56 x8\""
+        );
+    }
+}
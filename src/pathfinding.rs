@@ -0,0 +1,711 @@
+use crate::bitvec_set::{BitSet, BitSetKey};
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// A graph to search. Nodes are dense keys, as in a
+/// [`crate::bitvec_set::BitSet`], so search state lives in flat arrays rather
+/// than hash maps.
+pub trait Graph {
+    type Node: BitSetKey + PartialEq;
+
+    /// Bounds that every node is within.
+    fn bounds(&self) -> <Self::Node as BitSetKey>::Bounds;
+
+    /// The nodes one step from `node`, with the cost of that step.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u32)>;
+}
+
+/// A [`Graph`] made by [`from_fn`].
+pub struct FromFn<N: BitSetKey, F> {
+    bounds: N::Bounds,
+    neighbours: F,
+    node: PhantomData<N>,
+}
+
+/// Makes a [`Graph`] from its bounds and a function giving each node's
+/// neighbours and step costs.
+pub fn from_fn<N, F, I>(bounds: N::Bounds, neighbours: F) -> FromFn<N, F>
+where
+    N: BitSetKey + PartialEq,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    FromFn {
+        bounds,
+        neighbours,
+        node: PhantomData,
+    }
+}
+
+impl<N, F, I> Graph for FromFn<N, F>
+where
+    N: BitSetKey + PartialEq,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    type Node = N;
+
+    fn bounds(&self) -> N::Bounds {
+        self.bounds
+    }
+
+    fn neighbours(&self, node: N) -> impl Iterator<Item = (N, u32)> {
+        (self.neighbours)(node).into_iter()
+    }
+}
+
+const UNREACHED: u32 = u32::MAX;
+
+/// The outcome of a search: the distance to each node reached, and the node
+/// before it on one shortest path.
+///
+/// A search that stops at a goal has only settled nodes no further away than
+/// the goal. Distances to others may be too long or missing.
+#[derive(Debug, Clone)]
+pub struct Paths<N: BitSetKey> {
+    bounds: N::Bounds,
+    dist: Vec<u32>,
+    /// Key index of each node's predecessor, or `UNREACHED` for none.
+    pred: Vec<u32>,
+}
+
+impl<N: BitSetKey> Paths<N> {
+    fn new(bounds: N::Bounds) -> Paths<N> {
+        let capacity = N::capacity(bounds);
+        Paths {
+            bounds,
+            dist: vec![UNREACHED; capacity],
+            pred: vec![UNREACHED; capacity],
+        }
+    }
+
+    fn idx(&self, node: &N) -> usize {
+        debug_assert!(node.in_bounds(self.bounds), "node out of bounds");
+        node.index(self.bounds)
+    }
+
+    fn start(&mut self, node: N) {
+        let idx = self.idx(&node);
+        self.dist[idx] = 0;
+        self.pred[idx] = UNREACHED;
+    }
+
+    /// Records reaching `to` via `from`, if that's shorter than before.
+    fn relax(&mut self, from: N, to: N, dist: u32) -> bool {
+        let idx = self.idx(&to);
+        if dist < self.dist[idx] {
+            self.dist[idx] = dist;
+            self.pred[idx] = self.idx(&from) as u32;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<u32> {
+        if !node.in_bounds(self.bounds) {
+            return None;
+        }
+        Some(self.dist[self.idx(&node)]).filter(|&d| d != UNREACHED)
+    }
+
+    /// The node before `node` on a shortest path, or `None` for a start node
+    /// or one that wasn't reached.
+    pub fn predecessor(&self, node: N) -> Option<N> {
+        if !node.in_bounds(self.bounds) {
+            return None;
+        }
+        let pred = self.pred[self.idx(&node)];
+        (pred != UNREACHED).then(|| N::from_index(pred as usize, self.bounds))
+    }
+
+    /// A shortest path from a start node to `node`, including both ends.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        self.distance(node)?;
+
+        let mut path = vec![node];
+        while let Some(prev) = self.predecessor(*path.last().expect("never empty")) {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node reached and its distance, in order of key index.
+    pub fn reached(&self) -> impl Iterator<Item = (N, u32)> + use<'_, N> {
+        self.dist
+            .iter()
+            .enumerate()
+            .filter(|&(_, &d)| d != UNREACHED)
+            .map(|(i, &d)| (N::from_index(i, self.bounds), d))
+    }
+
+    /// Every step on any shortest path, as a DAG of optimal predecessors.
+    ///
+    /// `graph` must be the graph that was searched, and after a [`bfs`] every
+    /// step must cost 1, as that's what the search counted it as. Steps can be
+    /// missed if the search stopped at a goal, so search without one when using
+    /// this.
+    ///
+    /// Zero-cost steps can go round in circles, so this is only a DAG once
+    /// each such cycle is taken as a single node, as when counting paths.
+    pub fn shortest_path_dag<G: Graph<Node = N>>(&self, graph: &G) -> ShortestPathDag<N> {
+        let mut edges = Vec::new();
+        let mut zero_cost = Vec::new();
+        let mut starts = BitSet::new(self.bounds);
+
+        for (node, dist) in self.reached() {
+            let idx = self.idx(&node);
+            if dist == 0 && self.pred[idx] == UNREACHED {
+                starts.insert(node);
+            }
+
+            for (next, cost) in graph.neighbours(node) {
+                if self.distance(next) == Some(dist + cost) {
+                    let next = self.idx(&next) as u32;
+                    edges.push((next, idx as u32));
+                    if cost == 0 {
+                        zero_cost.push((idx as u32, next));
+                    }
+                }
+            }
+        }
+        let (component, cycles) = zero_cost_cycles(self.dist.len(), zero_cost);
+
+        // Group the edges by the node they lead to.
+        let mut offsets = vec![0; self.dist.len() + 1];
+        for &(to, _) in &edges {
+            offsets[to as usize + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        let mut preds = vec![0; edges.len()];
+        let mut next_slot = offsets.clone();
+        for (to, from) in edges {
+            preds[next_slot[to as usize]] = from;
+            next_slot[to as usize] += 1;
+        }
+
+        ShortestPathDag {
+            bounds: self.bounds,
+            offsets,
+            preds,
+            starts,
+            component,
+            cycles,
+        }
+    }
+
+    /// Distances by key index, with `u32::MAX` for nodes not reached. For grid
+    /// positions this is in row-major order, ready for `Array2::from_shape_vec`.
+    pub fn into_distances(self) -> Vec<u32> {
+        self.dist
+    }
+}
+
+/// Every step on any shortest path from a search's start nodes, see
+/// [`Paths::shortest_path_dag`].
+#[derive(Debug, Clone)]
+pub struct ShortestPathDag<N: BitSetKey> {
+    bounds: N::Bounds,
+    /// Key indexes of optimal predecessors, those of the node with key index
+    /// `i` being `preds[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    preds: Vec<u32>,
+    starts: BitSet<N>,
+    /// Key index of the zero-cost cycle each node is in, named by one of its
+    /// nodes, or empty if there are none.
+    component: Vec<u32>,
+    /// The nodes in each zero-cost cycle, by the name of the cycle.
+    cycles: FxHashMap<u32, Vec<u32>>,
+}
+
+impl<N: BitSetKey> ShortestPathDag<N> {
+    fn idx(&self, node: &N) -> usize {
+        assert!(node.in_bounds(self.bounds), "node out of bounds");
+        node.index(self.bounds)
+    }
+
+    fn preds(&self, i: usize) -> &[u32] {
+        &self.preds[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// The zero-cost cycle node `i` is in, or `i` itself if none.
+    fn component(&self, i: usize) -> usize {
+        self.component.get(i).map_or(i, |&c| c as usize)
+    }
+
+    /// The nodes in the zero-cost cycle named `c`, or just `c`.
+    fn members(&self, c: usize) -> impl Iterator<Item = usize> + '_ {
+        let members = self.cycles.get(&(c as u32)).map(Vec::as_slice);
+        members
+            .unwrap_or_default()
+            .iter()
+            .map(|&m| m as usize)
+            .chain(members.is_none().then_some(c))
+    }
+
+    /// The zero-cost cycles, or nodes, before those in `c` on any shortest
+    /// path to them.
+    fn component_preds(&self, c: usize) -> impl Iterator<Item = usize> + '_ {
+        self.members(c)
+            .flat_map(|m| self.preds(m))
+            .map(|&p| self.component(p as usize))
+            .filter(move |&p| p != c)
+    }
+
+    /// The nodes before `node` on any shortest path to it.
+    pub fn predecessors(&self, node: N) -> impl Iterator<Item = N> + use<'_, N> {
+        self.preds(self.idx(&node))
+            .iter()
+            .map(|&p| N::from_index(p as usize, self.bounds))
+    }
+
+    /// Every node on any shortest path to one of `goals`, including the goals.
+    /// To find the nodes on paths to the nearest of several goals, only pass
+    /// the nearest.
+    pub fn nodes_on_paths_to(&self, goals: impl IntoIterator<Item = N>) -> BitSet<N> {
+        let mut on_paths = BitSet::new(self.bounds);
+        let mut stack = goals.into_iter().collect::<Vec<_>>();
+
+        while let Some(node) = stack.pop() {
+            if on_paths.insert(node) {
+                stack.extend(self.predecessors(node));
+            }
+        }
+
+        on_paths
+    }
+
+    /// The number of distinct shortest paths to any of `goals`, which should
+    /// be distinct themselves. Saturates at `u64::MAX`.
+    ///
+    /// Paths that only differ in how they go round a zero-cost cycle are
+    /// counted once, as otherwise there would be infinitely many.
+    pub fn count_paths_to(&self, goals: impl IntoIterator<Item = N>) -> u64 {
+        let mut counts = vec![None; self.offsets.len() - 1];
+        let mut total = 0u64;
+
+        for goal in goals {
+            let goal = self.component(self.idx(&goal));
+            let mut stack = vec![goal];
+
+            // Depth-first, only counting a node's paths once all its
+            // predecessors' paths are counted.
+            while let Some(&i) = stack.last() {
+                if counts[i].is_some() {
+                    stack.pop();
+                    continue;
+                }
+
+                let pending = stack.len();
+                stack.extend(self.component_preds(i).filter(|&p| counts[p].is_none()));
+
+                if stack.len() == pending {
+                    let from_start = self
+                        .members(i)
+                        .any(|m| self.starts.contains(&N::from_index(m, self.bounds)))
+                        as u64;
+                    counts[i] = Some(self.component_preds(i).fold(from_start, |acc, p| {
+                        acc.saturating_add(counts[p].unwrap_or(0))
+                    }));
+                    stack.pop();
+                }
+            }
+
+            total = total.saturating_add(counts[goal].unwrap_or(0));
+        }
+
+        total
+    }
+}
+
+/// Finds the cycles among zero-cost `(from, to)` steps between `n` nodes, by
+/// Tarjan's algorithm. Returns the cycle each node is in, named by one of its
+/// nodes or the node itself if in none, and the nodes in each cycle. Both are
+/// empty if there are no steps.
+fn zero_cost_cycles(n: usize, mut steps: Vec<(u32, u32)>) -> (Vec<u32>, FxHashMap<u32, Vec<u32>>) {
+    let mut cycles = FxHashMap::default();
+    if steps.is_empty() {
+        return (Vec::new(), cycles);
+    }
+    steps.sort_unstable();
+    let first_step = |v: u32| steps.partition_point(|&(from, _)| from < v);
+
+    let mut component = (0..n as u32).collect::<Vec<_>>();
+    let mut index = vec![UNREACHED; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+
+    for &(root, _) in &steps {
+        if index[root as usize] != UNREACHED {
+            continue;
+        }
+
+        // Depth-first, each call being a node and the next of its steps to
+        // follow.
+        let mut calls = Vec::new();
+        let mut enter = Some(root);
+
+        loop {
+            if let Some(v) = enter.take() {
+                index[v as usize] = next_index;
+                low[v as usize] = next_index;
+                next_index += 1;
+                on_stack[v as usize] = true;
+                stack.push(v);
+                calls.push((v, first_step(v)));
+            }
+            let Some((v, step)) = calls.last_mut() else {
+                break;
+            };
+
+            let v = *v;
+            if let Some(&(from, to)) = steps.get(*step).filter(|&&(from, _)| from == v) {
+                *step += 1;
+                if index[to as usize] == UNREACHED {
+                    enter = Some(to);
+                } else if on_stack[to as usize] {
+                    low[from as usize] = low[from as usize].min(index[to as usize]);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(caller, _)) = calls.last() {
+                low[caller as usize] = low[caller as usize].min(low[v as usize]);
+            }
+            if low[v as usize] == index[v as usize] {
+                let first = stack.iter().rposition(|&m| m == v).unwrap();
+                let members = stack.split_off(first);
+                for &m in &members {
+                    on_stack[m as usize] = false;
+                    component[m as usize] = v;
+                }
+                if members.len() > 1 {
+                    cycles.insert(v, members);
+                }
+            }
+        }
+    }
+
+    (component, cycles)
+}
+
+/// Breadth-first search from `starts`, counting every step as 1 whatever its
+/// cost, and stopping once `goal` is reached if given.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: Option<G::Node>,
+) -> Paths<G::Node> {
+    let mut paths = Paths::new(graph.bounds());
+    let mut q = VecDeque::new();
+
+    for start in starts {
+        paths.start(start);
+        q.push_back(start);
+    }
+
+    while let Some(node) = q.pop_front() {
+        if Some(node) == goal {
+            break;
+        }
+        let next_dist = paths.dist[paths.idx(&node)] + 1;
+
+        for (next, _) in graph.neighbours(node) {
+            if paths.relax(node, next, next_dist) {
+                q.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Shortest paths from `starts` where every step costs 0 or 1, stopping once
+/// `goal` is settled if given.
+pub fn bfs_01<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: Option<G::Node>,
+) -> Paths<G::Node> {
+    let mut paths = Paths::new(graph.bounds());
+    let mut q = VecDeque::new();
+
+    for start in starts {
+        paths.start(start);
+        q.push_back((0, start));
+    }
+
+    while let Some((dist, node)) = q.pop_front() {
+        if dist > paths.dist[paths.idx(&node)] {
+            continue;
+        }
+        if Some(node) == goal {
+            break;
+        }
+
+        for (next, cost) in graph.neighbours(node) {
+            debug_assert!(cost <= 1, "0-1 BFS needs costs of 0 or 1");
+            if paths.relax(node, next, dist + cost) {
+                match cost {
+                    0 => q.push_front((dist, next)),
+                    _ => q.push_back((dist + 1, next)),
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's shortest paths from `starts`, stopping once `goal` is settled if
+/// given.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: Option<G::Node>,
+) -> Paths<G::Node> {
+    astar(graph, starts, goal, |_| 0)
+}
+
+/// A* search from `starts`, stopping once `goal` is settled if given.
+///
+/// `heuristic` estimates the distance from a node to the goal, and must be
+/// consistent: never more than a step's cost plus its estimate from the
+/// neighbour, and 0 at the goal.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: Option<G::Node>,
+    heuristic: impl Fn(G::Node) -> u32,
+) -> Paths<G::Node> {
+    let mut paths = Paths::new(graph.bounds());
+    let mut q = RadixHeap::default();
+
+    // Queue key indexes rather than nodes, which are cheaper to move around.
+    for start in starts {
+        paths.start(start);
+        q.push(heuristic(start), paths.idx(&start) as u32);
+    }
+
+    while let Some((estimate, idx)) = q.pop() {
+        let node = G::Node::from_index(idx as usize, paths.bounds);
+        let dist = paths.dist[idx as usize];
+        if estimate > dist + heuristic(node) {
+            continue;
+        }
+        if Some(node) == goal {
+            break;
+        }
+
+        for (next, cost) in graph.neighbours(node) {
+            if paths.relax(node, next, dist + cost) {
+                q.push(dist + cost + heuristic(next), paths.idx(&next) as u32);
+            }
+        }
+    }
+
+    paths
+}
+
+/// A priority queue popping the smallest key first, where keys pushed are
+/// never smaller than the last key popped, as in Dijkstra's algorithm.
+///
+/// Keys are bucketed by the highest bit they differ from the last popped key
+/// in, so each key is only moved between buckets a few times.
+pub struct RadixHeap<T> {
+    last: u32,
+    buckets: [Vec<(u32, T)>; 33],
+    len: usize,
+}
+
+impl<T> Default for RadixHeap<T> {
+    fn default() -> Self {
+        RadixHeap {
+            last: 0,
+            buckets: std::array::from_fn(|_| Vec::new()),
+            len: 0,
+        }
+    }
+}
+
+impl<T> RadixHeap<T> {
+    fn bucket(&self, key: u32) -> usize {
+        (u32::BITS - (key ^ self.last).leading_zeros()) as usize
+    }
+
+    pub fn push(&mut self, key: u32, value: T) {
+        assert!(key >= self.last, "key smaller than the last popped");
+        let bucket = self.bucket(key);
+        self.buckets[bucket].push((key, value));
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<(u32, T)> {
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            let items = std::mem::take(&mut self.buckets[i]);
+
+            self.last = items.iter().map(|&(k, _)| k).min().expect("not empty");
+            for (key, value) in items {
+                let bucket = self.bucket(key);
+                self.buckets[bucket].push((key, value));
+            }
+        }
+
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_util::{make_byte_grid, Grid};
+
+    const MAZE: &str = "S.#....
+.##.##.
+...#..E
+.#...#.
+";
+
+    fn maze() -> Grid<u8> {
+        make_byte_grid(MAZE).unwrap().into()
+    }
+
+    fn walk(grid: &Grid<u8>) -> impl Graph<Node = (usize, usize)> + '_ {
+        from_fn(grid.dim(), move |pos| {
+            grid.neighbours4(pos)
+                .filter(|&next| grid[next] != b'#')
+                .map(|next| (next, 1))
+        })
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let paths = bfs(&walk(&grid), [(0, 0)], None);
+
+        assert_eq!(paths.distance((2, 6)), Some(10));
+        assert_eq!(paths.distance((0, 2)), None);
+        assert_eq!(paths.predecessor((0, 0)), None);
+
+        let path = paths.path_to((2, 6)).unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path[0], (0, 0));
+        assert!(path
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = maze();
+        // Walking into a `.` costs 1, anything else is free.
+        let graph = from_fn(grid.dim(), |pos| {
+            grid.neighbours4(pos)
+                .filter(|&next| grid[next] != b'#')
+                .map(|next| (next, (grid[next] == b'.') as u32))
+                .collect::<Vec<_>>()
+        });
+        let end = grid.find(&b'E').unwrap();
+
+        let by_bfs_01 = bfs_01(&graph, [(0, 0)], None);
+        let by_dijkstra = dijkstra(&graph, [(0, 0)], None);
+        assert_eq!(
+            by_bfs_01.reached().collect::<Vec<_>>(),
+            by_dijkstra.reached().collect::<Vec<_>>()
+        );
+        assert_eq!(by_dijkstra.distance(end), Some(9));
+
+        let manhattan =
+            |pos: (usize, usize)| (pos.0.abs_diff(end.0) + pos.1.abs_diff(end.1)) as u32;
+        let by_astar = astar(&walk(&grid), [(0, 0)], Some(end), manhattan);
+        assert_eq!(by_astar.distance(end), Some(10));
+        assert_eq!(by_astar.path_to(end).unwrap().len(), 11);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let grid = maze();
+        let graph = walk(&grid);
+        let dag = bfs(&graph, [(0, 0)], None).shortest_path_dag(&graph);
+
+        assert_eq!(dag.count_paths_to([(2, 6)]), 1);
+        assert_eq!(dag.nodes_on_paths_to([(2, 6)]).len(), 11);
+        assert_eq!(dag.predecessors((0, 0)).count(), 0);
+        assert_eq!(dag.count_paths_to([(0, 0)]), 1);
+
+        let open = make_byte_grid("...\n...\n...\n").unwrap().into();
+        let graph = walk(&open);
+        let dag = dijkstra(&graph, [(0, 0)], None).shortest_path_dag(&graph);
+        assert_eq!(dag.count_paths_to([(2, 2)]), 6);
+        assert_eq!(dag.count_paths_to([(2, 2), (0, 2)]), 7);
+        assert_eq!(dag.nodes_on_paths_to([(1, 1)]).len(), 4);
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        let open: Grid<u8> = make_byte_grid("...\n...\n").unwrap().into();
+        // Moving along the top row is free, back and forth.
+        let graph = from_fn(open.dim(), |pos: (usize, usize)| {
+            open.neighbours4(pos)
+                .map(move |next| (next, (pos.0 + next.0 != 0) as u32))
+                .collect::<Vec<_>>()
+        });
+        let dag = bfs_01(&graph, [(0, 0)], None).shortest_path_dag(&graph);
+
+        assert_eq!(dag.count_paths_to([(0, 1)]), 1);
+        assert_eq!(dag.count_paths_to([(1, 2)]), 1);
+        assert_eq!(dag.count_paths_to([(1, 0), (1, 1), (1, 2)]), 3);
+        assert_eq!(dag.nodes_on_paths_to([(1, 2)]).len(), 4);
+    }
+
+    #[test]
+    fn test_zero_cost_step_beside_costly_one() {
+        // S -> A -> X costing 1 each, and S -> B -> X costing 2 then 0.
+        let [s, a, b, x] = [(0, 0), (0, 1), (0, 2), (0, 3)];
+        let graph = from_fn((1, 4), |node| match node {
+            n if n == s => vec![(a, 1), (b, 2)],
+            n if n == a => vec![(x, 1)],
+            n if n == b => vec![(x, 0)],
+            _ => vec![],
+        });
+        let dag = dijkstra(&graph, [s], None).shortest_path_dag(&graph);
+
+        assert_eq!(dag.count_paths_to([x]), 2);
+        assert_eq!(
+            dag.nodes_on_paths_to([x]).iter().collect::<Vec<_>>(),
+            [s, a, b, x]
+        );
+    }
+
+    #[test]
+    fn test_radix_heap() {
+        let mut heap = RadixHeap::default();
+        for key in [5, 1, 1000, 3, 64, 3] {
+            heap.push(key, ());
+        }
+        assert_eq!(heap.pop(), Some((1, ())));
+        heap.push(2, ());
+
+        let keys = std::iter::from_fn(|| heap.pop().map(|(k, _)| k)).collect::<Vec<_>>();
+        assert_eq!(keys, [2, 3, 3, 5, 64, 1000]);
+        assert!(heap.is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::report::Report;
+use crate::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
+};
+use crate::{solve_parts, solve_timed, Answer, ParseError, Parts, Solution};
+
+type SolveFn = fn(&str, Parts) -> Result<(Answer, Answer), ParseError>;
+
+/// A type-erased entry for one day, so days can be looked up and run by
+/// number.
+pub struct Day {
+    pub day: u8,
+    solve: SolveFn,
+    run: fn(&str, Parts) -> Result<Report, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            solve: solve_to_answers::<S>,
+            run: run_to_report::<S>,
+        }
+    }
+
+    /// Solves the requested parts, or reports why the input couldn't be
+    /// parsed. Parts not asked for are [`Answer::None`].
+    pub fn solve(&self, raw_inp: &str, parts: Parts) -> Result<(Answer, Answer), ParseError> {
+        (self.solve)(raw_inp, parts)
+    }
+
+    /// Solves the requested parts, timing everything after the input has been
+    /// read.
+    pub fn run(&self, raw_inp: &str, parts: Parts) -> Result<Report, ParseError> {
+        (self.run)(raw_inp, parts)
+    }
+
+    /// Conventional input file name for this day, e.g. `2024_06`.
+    pub fn input_name(&self) -> String {
+        format!("2024_{:02}", self.day)
+    }
+}
+
+fn to_answer(answer: Option<impl Into<Answer>>) -> Answer {
+    answer.map_or(Answer::None, Into::into)
+}
+
+fn solve_to_answers<S: Solution>(
+    raw_inp: &str,
+    parts: Parts,
+) -> Result<(Answer, Answer), ParseError> {
+    let (p1, p2) = solve_parts::<S>(raw_inp, parts)?;
+    Ok((to_answer(p1), to_answer(p2)))
+}
+
+fn run_to_report<S: Solution>(raw_inp: &str, parts: Parts) -> Result<Report, ParseError> {
+    let ((p1, p2), timings) = solve_timed::<S>(raw_inp, parts)?;
+
+    Ok(Report {
+        day: S::DAY,
+        part1: to_answer(p1),
+        part2: to_answer(p2),
+        timings,
+        bench: None,
+        check: None,
+    })
+}
+
+pub static DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
+
+/// Looks up a day by number (1-25).
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
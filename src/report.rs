@@ -0,0 +1,163 @@
+use crate::bench::BenchStats;
+use crate::manifest::Check;
+use crate::Answer;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// How the runners print their results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Just the answers, one per line
+    #[default]
+    Text,
+    /// One JSON object per day, one per line
+    Json,
+    /// Tab-separated, with a header row
+    Tsv,
+}
+
+impl Format {
+    /// Printed once before any reports, e.g. the TSV column names.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some(
+                "day\tpart1\tpart2\ttotal_ns\titerations\tmin_ns\tmedian_ns\tmean_ns\tstddev_ns\tparse_ns\tpart1_ns\tpart2_ns\tcheck",
+            ),
+            Format::Text | Format::Json => None,
+        }
+    }
+}
+
+pub(crate) fn as_nanos<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u64(d.as_nanos() as u64)
+}
+
+/// How long one run of a day took, in total and per phase.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct Timings {
+    /// Parsing and solving both parts, excluding reading the input.
+    #[serde(rename = "total_ns", serialize_with = "as_nanos")]
+    pub total: Duration,
+    #[serde(rename = "parse_ns", serialize_with = "as_nanos")]
+    pub parse: Duration,
+    #[serde(rename = "part1_ns", serialize_with = "as_nanos")]
+    pub part1: Duration,
+    #[serde(rename = "part2_ns", serialize_with = "as_nanos")]
+    pub part2: Duration,
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        write!(
+            f,
+            "parse {:.3} ms, part 1 {:.3} ms, part 2 {:.3} ms, total {:.3} ms",
+            ms(self.parse),
+            ms(self.part1),
+            ms(self.part2),
+            ms(self.total)
+        )
+    }
+}
+
+/// The outcome of running one day. Field names are the JSON schema, so only
+/// ever add to them.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    /// Only present when benchmarking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// Only present when checking against expected answers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<Check>,
+}
+
+impl Report {
+    /// Renders the report, including a trailing newline where there is any
+    /// output at all.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => [&self.part1, &self.part2]
+                .into_iter()
+                .filter(|&answer| answer != &Answer::None)
+                .map(|answer| format!("{}\n", answer))
+                .chain(self.bench.map(|stats| format!("{}\n", stats)))
+                .chain(
+                    self.check
+                        .as_ref()
+                        .map(|check| format!("check: {}\n", check)),
+                )
+                .collect(),
+            Format::Json => serde_json::to_string(self).expect("reports always serialize") + "\n",
+            Format::Tsv => {
+                let bench = match self.bench {
+                    Some(stats) => format!(
+                        "{}\t{}\t{}\t{}\t{}",
+                        stats.iterations,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.mean.as_nanos(),
+                        stats.stddev.as_nanos()
+                    ),
+                    None => "\t\t\t\t".to_string(),
+                };
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                    self.day,
+                    self.part1,
+                    self.part2,
+                    self.timings.total.as_nanos(),
+                    bench,
+                    self.timings.parse.as_nanos(),
+                    self.timings.part1.as_nanos(),
+                    self.timings.part2.as_nanos(),
+                    self.check.as_ref().map(Check::status).unwrap_or_default()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            day: 25,
+            part1: Answer::Integer(3162),
+            part2: Answer::None,
+            timings: Timings {
+                total: Duration::from_micros(42),
+                parse: Duration::from_micros(2),
+                part1: Duration::from_micros(10),
+                part2: Duration::from_micros(30),
+            },
+            bench: None,
+            check: None,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(report().render(Format::Text), "3162\n");
+        assert_eq!(
+            report().timings.to_string(),
+            "parse 0.002 ms, part 1 0.010 ms, part 2 0.030 ms, total 0.042 ms"
+        );
+        assert_eq!(
+            report().render(Format::Json),
+            "{\"day\":25,\"part1\":3162,\"part2\":null,\"timings\":{\"total_ns\":42000,\"parse_ns\":2000,\"part1_ns\":10000,\"part2_ns\":30000}}\n"
+        );
+        assert_eq!(
+            report().render(Format::Tsv),
+            "25\t3162\t\t42000\t\t\t\t\t\t2000\t10000\t30000\t\n"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::batch::{self, Batch, BatchEntry};
+use crate::input::InputSource;
+use crate::manifest::{Check, Manifest};
+use crate::report::Format;
+use crate::{registry, Cli, Parser};
+use std::{fs, path::Path, process};
+
+/// Entry point shared by the per-day `2024_NN` binaries.
+pub fn day_main(day: u8) {
+    let args = Cli::parse();
+    args.run.threads.apply();
+    let day = registry::get(day).expect("unknown day");
+
+    if args.day.is_some_and(|d| d != day.day) {
+        eprintln!("this binary only solves day {}", day.day);
+        process::exit(2);
+    }
+
+    let sources = args.run.input_sources(day).unwrap_or_else(|e| {
+        eprintln!("can't list inputs: {}", e);
+        process::exit(1);
+    });
+    let manifest = args.run.check.then(|| load_manifest(&args.run.answers));
+
+    if args.run.is_batch() {
+        let batch = args.run.batch(day, manifest.as_ref());
+        if run_batch(&batch, &sources, args.run.format) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let source = &sources[0];
+    let inp = source.read().unwrap_or_else(|e| {
+        eprintln!("can't open input {}: {}", source, e);
+        process::exit(1);
+    });
+
+    match args.run.bench.run(day, &inp, args.run.part) {
+        Ok(mut report) => {
+            if let Some(manifest) = &manifest {
+                let key = source.manifest_key(&args.run.input_dir);
+                report.check = Some(manifest.check(key.as_deref(), &report, args.run.part));
+            }
+            let compared = args.run.threads.compare(day, &inp, args.run.part, &report);
+
+            if let Some(header) = args.run.format.header() {
+                println!("{}", header);
+            }
+            print!("{}", report.render(args.run.format));
+            if args.run.timings && args.run.format == Format::Text {
+                println!("{}", report.timings);
+            }
+            if let Err(e) = compared {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+            if matches!(report.check, Some(Check::Fail { .. })) {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("can't parse input: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Solves a batch of inputs and prints the outcomes, returning whether any
+/// failed.
+pub fn run_batch(batch: &Batch, sources: &[InputSource], format: Format) -> bool {
+    let entries = batch.run(sources);
+    print!("{}", batch::render(&entries, format));
+    entries.iter().any(BatchEntry::failed)
+}
+
+/// Loads the expected answers for `--check`, exiting if they can't be read.
+pub fn load_manifest(path: &Path) -> Manifest {
+    let raw = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("can't open answers file {}: {}", path.display(), e);
+        process::exit(1);
+    });
+
+    Manifest::parse(&raw).unwrap_or_else(|e| {
+        eprintln!("can't parse answers file {}: {}", path.display(), e);
+        process::exit(1);
+    })
+}
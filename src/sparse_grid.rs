@@ -0,0 +1,187 @@
+use ahash::AHashMap;
+use ndarray::Array2;
+
+/// A grid keyed by signed `(row, column)` positions, with no bounds fixed up
+/// front. Only cells that have been set are stored.
+///
+/// Tracks the bounding box of everything ever inserted, so it can be converted
+/// to a dense `Array2`, e.g. for rendering with [`crate::grid_util::Render`].
+/// Equality only depends on the cells that are set, not the bounds.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: AHashMap<(i64, i64), T>,
+    min: (i64, i64),
+    max: (i64, i64),
+}
+
+/// A set of signed positions.
+pub type SparseSet = SparseGrid<()>;
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: AHashMap::default(),
+            min: (i64::MAX, i64::MAX),
+            max: (i64::MIN, i64::MIN),
+        }
+    }
+
+    /// Sets a cell, returning its previous value, growing the bounds if needed.
+    pub fn insert(&mut self, pos: (i64, i64), value: T) -> Option<T> {
+        self.min = (self.min.0.min(pos.0), self.min.1.min(pos.1));
+        self.max = (self.max.0.max(pos.0), self.max.1.max(pos.1));
+        self.cells.insert(pos, value)
+    }
+
+    pub fn get(&self, pos: &(i64, i64)) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: &(i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    pub fn contains(&self, pos: &(i64, i64)) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    /// Clears a cell. The bounds never shrink.
+    pub fn remove(&mut self, pos: &(i64, i64)) -> Option<T> {
+        self.cells.remove(pos)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The cells that are set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> + use<'_, T> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The smallest and largest corners of everything ever inserted, if
+    /// anything has been.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        (self.min.0 <= self.max.0).then_some((self.min, self.max))
+    }
+
+    /// Converts to a dense grid covering [`SparseGrid::bounds`], mapping every
+    /// cell, set or not, through `f`. Also returns the position of the dense
+    /// grid's `(0, 0)`, so `pos` is at `(pos.0 - origin.0, pos.1 - origin.1)`.
+    pub fn to_dense<U>(&self, f: impl Fn(Option<&T>) -> U) -> (Array2<U>, (i64, i64)) {
+        let Some((min, max)) = self.bounds() else {
+            return (Array2::from_shape_fn((0, 0), |_| f(None)), (0, 0));
+        };
+
+        let shape = ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize);
+        let dense = Array2::from_shape_fn(shape, |(r, c)| {
+            f(self.get(&(min.0 + r as i64, min.1 + c as i64)))
+        });
+        (dense, min)
+    }
+}
+
+impl SparseSet {
+    /// Adds a position, returning whether it was newly added.
+    pub fn add(&mut self, pos: (i64, i64)) -> bool {
+        self.insert(pos, ()).is_none()
+    }
+
+    /// Converts to a dense grid of which positions are in the set, and the
+    /// position of its `(0, 0)`.
+    pub fn to_bool_grid(&self) -> (Array2<bool>, (i64, i64)) {
+        self.to_dense(|cell| cell.is_some())
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &SparseGrid<T>) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> Extend<((i64, i64), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((i64, i64), T)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(pos, value)| {
+            self.insert(pos, value);
+        });
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl Extend<(i64, i64)> for SparseSet {
+    fn extend<I: IntoIterator<Item = (i64, i64)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|pos| {
+            self.add(pos);
+        });
+    }
+}
+
+impl FromIterator<(i64, i64)> for SparseSet {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> SparseSet {
+        let mut set = SparseSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_util::Render;
+
+    #[test]
+    fn test_grows() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((-3, 2), b'a');
+        grid.insert((1, -4), b'b');
+        assert_eq!(grid.insert((1, -4), b'c'), Some(b'b'));
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(&(1, -4)), Some(&b'c'));
+        assert_eq!(grid.bounds(), Some(((-3, -4), (1, 2))));
+
+        let (dense, origin) = grid.to_dense(|cell| cell.copied().unwrap_or(b'.'));
+        assert_eq!(origin, (-3, -4));
+        assert_eq!(dense.dim(), (5, 7));
+        assert_eq!(dense[(0, 6)], b'a');
+        assert_eq!(dense[(4, 0)], b'c');
+    }
+
+    #[test]
+    fn test_set() {
+        let mut set = [(0, 0), (-1, 1)].into_iter().collect::<SparseSet>();
+        assert!(!set.add((0, 0)));
+        assert!(set.contains(&(-1, 1)));
+
+        let mut other = [(0, 0), (-1, 1), (5, 5)].into_iter().collect::<SparseSet>();
+        assert_ne!(set, other);
+        other.remove(&(5, 5));
+        assert_eq!(set, other);
+
+        let (dense, origin) = set.to_bool_grid();
+        assert_eq!(origin, (-1, 0));
+        assert_eq!(dense.render().to_string(), ".#\n#.\n");
+    }
+}
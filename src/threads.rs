@@ -0,0 +1,106 @@
+use crate::registry::Day;
+use crate::report::Report;
+use crate::Parts;
+use clap::Args;
+use rayon::ThreadPoolBuilder;
+
+/// Command line options for how many threads the solvers use, shared by the
+/// runners.
+#[derive(Debug, Clone, Copy, Args)]
+pub struct ThreadArgs {
+    /// Threads for the parallel days to use, one per CPU by default
+    #[clap(long, conflicts_with = "sequential", value_parser = clap::value_parser!(u32).range(1..))]
+    pub threads: Option<u32>,
+
+    /// Run everything on a single thread, the same as `--threads 1`
+    #[clap(long)]
+    pub sequential: bool,
+
+    /// Also solve each day on a single thread, failing if the answers differ
+    #[clap(long, conflicts_with = "sequential")]
+    pub compare_sequential: bool,
+}
+
+impl ThreadArgs {
+    /// The number of threads asked for, if any.
+    pub fn num_threads(&self) -> Option<usize> {
+        match self.sequential {
+            true => Some(1),
+            false => self.threads.map(|n| n as usize),
+        }
+    }
+
+    /// Sizes rayon's global thread pool. Must be called before anything uses
+    /// rayon, i.e. before any day is solved.
+    pub fn apply(&self) {
+        if let Some(threads) = self.num_threads() {
+            ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build_global()
+                .expect("thread pool already started");
+        }
+    }
+
+    /// With `--compare-sequential`, solves `day` again on a single thread and
+    /// describes any difference from the answers in `report`.
+    pub fn compare(
+        &self,
+        day: &Day,
+        raw_inp: &str,
+        parts: Parts,
+        report: &Report,
+    ) -> Result<(), String> {
+        if !self.compare_sequential {
+            return Ok(());
+        }
+
+        match sequentially(|| day.solve(raw_inp, parts)) {
+            Ok((p1, p2)) if (&p1, &p2) == (&report.part1, &report.part2) => Ok(()),
+            Ok((p1, p2)) => Err(format!(
+                "answers differ on a single thread: {} and {} rather than {} and {}",
+                p1, p2, report.part1, report.part2
+            )),
+            Err(e) => Err(format!("can't parse input on a single thread: {}", e)),
+        }
+    }
+}
+
+/// Runs `f` in a pool of its own with a single thread, so anything it does
+/// with rayon runs sequentially, whatever the global pool's size.
+pub fn sequentially<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("can't start a thread")
+        .install(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_sequentially() {
+        assert_eq!(sequentially(rayon::current_num_threads), 1);
+    }
+
+    #[test]
+    fn test_parallel_days_match() {
+        // Examples for the days that use rayon.
+        let examples = [
+            (6, "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...\n"),
+            (19, "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\nbggr\ngbbr\nrrbgbr\nubwu\nbwurrg\nbrgr\nbbrgwb\n"),
+            (22, "1\n2\n3\n2024\n"),
+        ];
+
+        for (day, raw_inp) in examples {
+            let day = registry::get(day).unwrap();
+            let parallel = day.solve(raw_inp, Parts::Both).unwrap();
+            assert_eq!(
+                sequentially(|| day.solve(raw_inp, Parts::Both)).unwrap(),
+                parallel
+            );
+        }
+    }
+}